@@ -0,0 +1,359 @@
+#!/usr/bin/env python3
+#
+# Generates `src/tables.rs` from the Unicode Character Database of the version
+# `std` follows, a directory holding UnicodeData.txt, SpecialCasing.txt,
+# PropList.txt and CaseFolding.txt from
+# https://www.unicode.org/Public/17.0.0/ucd/.
+#
+#     python3 scripts/tables.py ucd > src/tables.rs
+
+import os
+import re
+import sys
+
+VOWEL         = 1 << 0
+ACCENT        = 1 << 1
+DIALYTIKA     = 1 << 2
+YPOGEGRAMMENI = 1 << 3
+
+ACCENTS = {0x0300, 0x0301, 0x0302, 0x0303, 0x0311, 0x0342}
+
+CATEGORY      = {}
+COMBINING     = {}
+DECOMPOSITION = {}
+UPPER         = {}
+TITLE         = {}
+SPECIAL       = {}
+RANGES        = []
+VERSIONS      = set()
+
+def lines(name):
+	with open(os.path.join(sys.argv[1], name), encoding="utf-8") as file:
+		for line in file:
+			match = re.match(r"# [A-Za-z]+-(\d+\.\d+\.\d+)\.txt", line)
+
+			if match:
+				VERSIONS.add(match.group(1))
+
+			line = line.split("#", 1)[0].strip()
+
+			if line:
+				yield [field.strip() for field in line.split(";")]
+
+def codes(field):
+	return [int(code, 16) for code in field.split()]
+
+def load():
+	first = None
+
+	for fields in lines("UnicodeData.txt"):
+		code = int(fields[0], 16)
+
+		# Ranges only give the properties of their first and last character.
+		if fields[1].endswith(", First>"):
+			first = code
+			continue
+
+		if fields[1].endswith(", Last>"):
+			RANGES.append((first, code, fields[2], int(fields[3])))
+			continue
+
+		CATEGORY[code]  = fields[2]
+		COMBINING[code] = int(fields[3])
+
+		if fields[5]:
+			compat = fields[5].startswith("<")
+			DECOMPOSITION[code] = (compat, codes(fields[5].split(">")[-1]))
+
+		if fields[12]:
+			UPPER[code] = int(fields[12], 16)
+
+		# An empty titlecase mapping is the same as the uppercase one.
+		if fields[14] or fields[12]:
+			TITLE[code] = int(fields[14] or fields[12], 16)
+
+	# Only the mappings without conditions, like `std` and `str.upper`.
+	for fields in lines("SpecialCasing.txt"):
+		if len(fields) < 5 or not fields[4]:
+			SPECIAL[int(fields[0], 16)] = ("".join(map(chr, codes(fields[2]))), "".join(map(chr, codes(fields[3]))))
+
+def category(code):
+	if code in CATEGORY:
+		return CATEGORY[code]
+
+	for first, last, value, _ in RANGES:
+		if first <= code <= last:
+			return value
+
+	return "Cn"
+
+def combining_class(code):
+	return COMBINING.get(code, 0)
+
+def decompose(code, compat):
+	if code not in DECOMPOSITION or (DECOMPOSITION[code][0] and not compat):
+		return chr(code)
+
+	return "".join(decompose(part, compat) for part in DECOMPOSITION[code][1])
+
+def upper(code):
+	return SPECIAL[code][1] if code in SPECIAL else chr(UPPER.get(code, code))
+
+def title(code):
+	return SPECIAL[code][0] if code in SPECIAL else chr(TITLE.get(code, code))
+
+def char(code):
+	if code < 0x80 and chr(code).isprintable() and chr(code) not in "'\\":
+		return "'%s'" % chr(code)
+
+	return "'\\u{%x}'" % code
+
+def string(text):
+	return '"%s"' % "".join(ch if ord(ch) < 0x80 and ch.isprintable() and ch not in '"\\' else "\\u{%x}" % ord(ch) for ch in text)
+
+def titlecase():
+	entries = []
+
+	for code in range(0x110000):
+		if title(code) != upper(code):
+			entries.append((code, title(code)))
+
+	return entries
+
+def greek():
+	entries = []
+
+	for code in list(range(0x0370, 0x0400)) + list(range(0x1f00, 0x2000)):
+		if category(code) not in ("Lu", "Ll", "Lt"):
+			continue
+
+		decomposed  = decompose(code, False)
+		base, marks = decomposed[0], decomposed[1:]
+
+		if not marks:
+			continue
+
+		capital = upper(ord(base))
+
+		if len(capital) != 1:
+			continue
+
+		flags = 0
+
+		if capital in "ΑΕΗΙΟΥΩ":
+			flags |= VOWEL
+
+		for mark in marks:
+			if ord(mark) in ACCENTS:
+				flags |= ACCENT
+			elif ord(mark) == 0x0308:
+				flags |= DIALYTIKA
+			elif ord(mark) == 0x0345:
+				flags |= YPOGEGRAMMENI
+
+		entries.append((code, ord(capital), flags))
+
+	return entries
+
+def soft_dotted():
+	ranges = []
+
+	for fields in lines("PropList.txt"):
+		if fields[1] != "Soft_Dotted":
+			continue
+
+		first, _, last = fields[0].partition("..")
+		ranges.append((int(first, 16), int(last or first, 16)))
+
+	return ranges
+
+def combining():
+	ranges = []
+
+	for code in range(0x110000):
+		klass = combining_class(code)
+
+		if klass == 0:
+			continue
+
+		if ranges and ranges[-1][1] == code - 1 and ranges[-1][2] == klass:
+			ranges[-1][1] = code
+		else:
+			ranges.append([code, code, klass])
+
+	return ranges
+
+def marks():
+	ranges = []
+
+	for code in range(0x110000):
+		if not category(code).startswith("M"):
+			continue
+
+		if ranges and ranges[-1][1] == code - 1:
+			ranges[-1][1] = code
+		else:
+			ranges.append([code, code])
+
+	return ranges
+
+# Letters that don't decompose to an ASCII letter.
+LATIN = {
+	"ß": "ss", "æ": "ae", "ð": "d", "ø": "o", "þ": "th", "đ": "d", "ħ": "h",
+	"ı": "i", "ĸ": "k", "ł": "l", "ŋ": "ng", "œ": "oe", "ŧ": "t", "ƀ": "b",
+	"ƃ": "b", "ƈ": "c", "ƌ": "d", "ƒ": "f", "ƙ": "k", "ƚ": "l", "ƞ": "n",
+	"ƥ": "p", "ƫ": "t", "ƭ": "t", "ƴ": "y", "ƶ": "z", "ǝ": "e", "ǥ": "g",
+	"ȡ": "d", "ȥ": "z", "ȴ": "l", "ȵ": "n", "ȶ": "t", "ȷ": "j", "ȼ": "c",
+	"ȿ": "s", "ɀ": "z", "ɇ": "e", "ɉ": "j", "ɋ": "q", "ɍ": "r", "ɏ": "y",
+	"ɓ": "b", "ɔ": "o", "ɗ": "d", "ə": "e", "ɛ": "e", "ɠ": "g", "ɨ": "i",
+	"ɲ": "n", "ʈ": "t", "ʉ": "u", "ʋ": "v", "ʒ": "z",
+}
+
+# ELOT 743, `ου` is handled separately.
+GREEK = {
+	"α": "a", "β": "v", "γ": "g", "δ": "d", "ε": "e", "ζ": "z", "η": "i",
+	"θ": "th", "ι": "i", "κ": "k", "λ": "l", "μ": "m", "ν": "n", "ξ": "x",
+	"ο": "o", "π": "p", "ρ": "r", "σ": "s", "ς": "s", "τ": "t", "υ": "y",
+	"φ": "f", "χ": "ch", "ψ": "ps", "ω": "o",
+}
+
+# ICAO 9303, including the Ukrainian, Belarusian, Serbian and Macedonian
+# letters.
+CYRILLIC = {
+	"а": "a", "б": "b", "в": "v", "г": "g", "д": "d", "е": "e", "ж": "zh",
+	"з": "z", "и": "i", "й": "i", "к": "k", "л": "l", "м": "m", "н": "n",
+	"о": "o", "п": "p", "р": "r", "с": "s", "т": "t", "у": "u", "ф": "f",
+	"х": "kh", "ц": "ts", "ч": "ch", "ш": "sh", "щ": "shch", "ъ": "ie",
+	"ы": "y", "ь": "", "э": "e", "ю": "iu", "я": "ia", "ђ": "d", "є": "ie",
+	"ѕ": "dz", "і": "i", "ј": "j", "љ": "lj", "њ": "nj", "ћ": "c", "џ": "dz",
+	"ґ": "g",
+}
+
+SCRIPTS = [
+	(0x00c0, 0x0250), (0x0370, 0x0400), (0x0400, 0x0530), (0x1e00, 0x1f00),
+	(0x1f00, 0x2000), (0xfb00, 0xfb07),
+]
+
+def ascii():
+	entries = []
+	table   = dict(LATIN, **GREEK, **CYRILLIC)
+
+	for start, end in SCRIPTS:
+		for code in range(start, end):
+			ch = chr(code)
+
+			if category(code) != "Ll":
+				continue
+
+			if ch in table:
+				entries.append((code, table[ch]))
+				continue
+
+			decomposed = decompose(code, True)
+			letters    = [table.get(c, c) for c in decomposed if not combining_class(ord(c))]
+			result     = "".join(letters)
+
+			if result and result.isascii() and result.isalpha():
+				entries.append((code, result.lower()))
+
+	return sorted(entries)
+
+def folding():
+	simple  = []
+	full    = []
+	turkic  = []
+
+	for code, status, mapping, *_ in lines("CaseFolding.txt"):
+		code    = int(code, 16)
+		mapping = "".join(map(chr, codes(mapping)))
+
+		if status in ("C", "S"):
+			simple.append((code, ord(mapping)))
+		elif status == "F":
+			full.append((code, mapping))
+		elif status == "T":
+			turkic.append((code, ord(mapping)))
+
+	return sorted(simple), sorted(full), sorted(turkic)
+
+out = sys.stdout
+
+load()
+
+simple, full, turkic = folding()
+
+# All the files must come from the same version.
+assert len(VERSIONS) == 1, VERSIONS
+
+out.write("// Generated by `scripts/tables.py` from Unicode %s, do not edit.\n" % VERSIONS.pop())
+out.write("\n")
+out.write("/// Greek letters with diacritics, with their upper case base letter and the\n")
+out.write("/// diacritics they carry, see `greek::upper`.\n")
+out.write("pub const GREEK: &[(char, char, u8)] = &[\n")
+for code, capital, flags in greek():
+	out.write("\t(%s, %s, %d),\n" % (char(code), char(capital), flags))
+out.write("];\n")
+
+out.write("\n")
+out.write("/// Ranges of characters with a non zero canonical combining class, with the\n")
+out.write("/// class.\n")
+out.write("pub const COMBINING: &[(char, char, u8)] = &[\n")
+for start, end, klass in combining():
+	out.write("\t(%s, %s, %d),\n" % (char(start), char(end), klass))
+out.write("];\n")
+
+out.write("\n")
+out.write("/// Ranges of combining marks, any character in the Mn, Mc and Me general\n")
+out.write("/// categories, even with a zero combining class.\n")
+out.write("pub const MARKS: &[(char, char)] = &[\n")
+for start, end in marks():
+	out.write("\t(%s, %s),\n" % (char(start), char(end)))
+out.write("];\n")
+
+out.write("\n")
+out.write("/// Ranges of characters with the Soft_Dotted property, losing their dot when\n")
+out.write("/// followed by an accent.\n")
+out.write("pub const SOFT_DOTTED: &[(char, char)] = &[\n")
+for start, end in soft_dotted():
+	out.write("\t(%s, %s),\n" % (char(start), char(end)))
+out.write("];\n")
+
+out.write("\n")
+out.write("/// Characters with a title case mapping different from the upper case one,\n")
+out.write("/// `ǆ` to `ǅ` instead of `Ǆ`.\n")
+out.write("pub const TITLECASE: &[(char, &str)] = &[\n")
+for code, mapping in titlecase():
+	out.write("\t(%s, %s),\n" % (char(code), string(mapping)))
+out.write("];\n")
+
+out.write("\n")
+out.write("/// Lower case Latin, Greek and Cyrillic letters with their transliteration\n")
+out.write("/// to lower case ASCII, see `Casing::slug`.\n")
+out.write("pub const ASCII: &[(char, &str)] = &[\n")
+for code, ascii in ascii():
+	out.write("\t(%s, %s),\n" % (char(code), string(ascii)))
+out.write("];\n")
+
+out.write("\n")
+out.write("/// Characters with a simple case folding, the C and S mappings of\n")
+out.write("/// CaseFolding.txt.\n")
+out.write("pub const SIMPLE_FOLDING: &[(char, char)] = &[\n")
+for code, folded in simple:
+	out.write("\t(%s, %s),\n" % (char(code), char(folded)))
+out.write("];\n")
+
+out.write("\n")
+out.write("/// Characters with a full case folding to more than one character, the F\n")
+out.write("/// mappings of CaseFolding.txt.\n")
+out.write("pub const FULL_FOLDING: &[(char, &str)] = &[\n")
+for code, folded in full:
+	out.write("\t(%s, %s),\n" % (char(code), string(folded)))
+out.write("];\n")
+
+out.write("\n")
+out.write("/// Characters folding differently in Turkic locales, the T mappings of\n")
+out.write("/// CaseFolding.txt.\n")
+out.write("pub const TURKIC_FOLDING: &[(char, char)] = &[\n")
+for code, folded in turkic:
+	out.write("\t(%s, %s),\n" % (char(code), char(folded)))
+out.write("];\n")
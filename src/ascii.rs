@@ -1,33 +1,24 @@
 use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::hash::Hasher;
 use std::iter::{Cloned, Enumerate};
-use std::ascii::AsciiExt;
 use std::slice;
-use {Casing, Separator, Locale};
-
-#[inline(always)]
-fn is_ascii_uppercase(b: u8) -> bool {
-	b >= b'A' && b <= b'Z'
-}
-
-#[inline(always)]
-fn is_ascii_lowercase(b: u8) -> bool {
-	b >= b'a' && b <= b'z'
-}
-
-#[inline(always)]
-fn is_ascii_alphabetic(b: u8) -> bool {
-	(b >= b'A' && b <= b'Z') || (b >= b'a' && b <= b'z')
-}
+use std::str;
+use {Casing, Converter, Camel, Capital, FoldMode, Case, Cases, Body, Style, Separator, Locale, Boundary, Symbols, Slug};
+use buffer::Buffer;
+use fold::FoldedBytes;
+use headline::{self, Rules};
+use words::{Words, Word, Kind, Pattern, Acronyms};
 
 impl Casing for [u8] {
 	type Character = u8;
 
-	fn upper(&self, _locale: Locale) -> Cow<Self> {
+	fn upper(&self, _locale: Locale) -> Cow<'_, Self> {
 		let mut chars = self.iter().cloned().enumerate();
 
 		while let Some((start, ch)) = chars.next() {
 			// There's a lower case character, gotta copy the string.
-			if !is_ascii_uppercase(ch) && is_ascii_alphabetic(ch) {
+			if !ch.is_ascii_uppercase() && ch.is_ascii_alphabetic() {
 				return Cow::Owned(owned(self, chars, (start, ch)));
 			}
 		}
@@ -47,7 +38,7 @@ impl Casing for [u8] {
 			// result or extend with the upper case version if a lower case
 			// character is found.
 			for (i, ch) in chars {
-				if !is_ascii_uppercase(ch) && is_ascii_alphabetic(ch) {
+				if !ch.is_ascii_uppercase() && ch.is_ascii_alphabetic() {
 					if let Some(offset) = leftover.take() {
 						result.extend_from_slice(&this[offset .. i]);
 					}
@@ -68,12 +59,12 @@ impl Casing for [u8] {
 		}
 	}
 
-	fn lower(&self, _locale: Locale) -> Cow<Self> {
+	fn lower(&self, _locale: Locale) -> Cow<'_, Self> {
 		let mut chars = self.iter().cloned().enumerate();
 
 		while let Some((start, ch)) = chars.next() {
 			// There's a lower case character, gotta copy the string.
-			if !is_ascii_lowercase(ch) && is_ascii_alphabetic(ch) {
+			if !ch.is_ascii_lowercase() && ch.is_ascii_alphabetic() {
 				return Cow::Owned(owned(self, chars, (start, ch)));
 			}
 		}
@@ -93,7 +84,7 @@ impl Casing for [u8] {
 			// result or extend with the upper case version if a lower case
 			// character is found.
 			for (i, ch) in chars {
-				if !is_ascii_lowercase(ch) && is_ascii_alphabetic(ch) {
+				if !ch.is_ascii_lowercase() && ch.is_ascii_alphabetic() {
 					if let Some(offset) = leftover.take() {
 						result.extend_from_slice(&this[offset .. i]);
 					}
@@ -114,18 +105,73 @@ impl Casing for [u8] {
 		}
 	}
 
-	fn capitalized(&self, _locale: Locale) -> Cow<Self> {
+	fn fold(&self, mode: FoldMode, locale: Locale) -> Cow<'_, Self> {
+		// Valid UTF-8 is folded like any string, so `I` follows the locale, and
+		// invalid bytes are kept as they are.
+		let mut result = Buffer::<[u8]>::new(self);
+
+		for chunk in self.utf8_chunks() {
+			result.push_slice(chunk.valid().fold(mode, locale).as_bytes());
+			result.push_slice(chunk.invalid());
+		}
+
+		result.finish()
+	}
+
+	fn eq_ignore_case(&self, other: &Self, locale: Locale) -> bool {
+		// ASCII only folds to ASCII, outside of Turkic locales.
+		if !locale.is_turkic() && self.is_ascii() && other.is_ascii() {
+			return self.eq_ignore_ascii_case(other);
+		}
+
+		FoldedBytes::new(self, locale).eq(FoldedBytes::new(other, locale))
+	}
+
+	fn cmp_ignore_case(&self, other: &Self, locale: Locale) -> Ordering {
+		FoldedBytes::new(self, locale).cmp(FoldedBytes::new(other, locale))
+	}
+
+	fn hash_folded<H: Hasher>(&self, state: &mut H, locale: Locale) {
+		// Bytes are hashed in chunks to avoid a call for each of them.
+		let mut chunk  = [0; 64];
+		let mut length = 0;
+
+		for byte in FoldedBytes::new(self, locale) {
+			if length == chunk.len() {
+				state.write(&chunk);
+				length = 0;
+			}
+
+			chunk[length] = byte;
+			length += 1;
+		}
+
+		state.write(&chunk[.. length]);
+		state.write_u8(0xff);
+	}
+
+	fn capitalized(&self, capital: Capital, _locale: Locale) -> Cow<'_, Self> {
+		// Anything before the first letter or digit is left as it is.
+		let start = match capital {
+			Capital::Letter => self.iter().position(u8::is_ascii_alphanumeric).unwrap_or(self.len()),
+			Capital::First  => 0,
+		};
+
 		let mut chars = self.iter().cloned().enumerate();
 
+		if start > 0 {
+			chars.nth(start - 1);
+		}
+
 		if let Some((start, ch)) = chars.next() {
 			// If the first letter is already uppercase we don't need to do anything.
-			if !is_ascii_uppercase(ch) && is_ascii_alphabetic(ch) {
+			if !ch.is_ascii_uppercase() && ch.is_ascii_alphabetic() {
 				return Cow::Owned(owned(self, chars, (start, ch), true));
 			}
 
 			while let Some((start, ch)) = chars.next() {
 				// There's an upper case character, gotta copy the string.
-				if !is_ascii_lowercase(ch) && is_ascii_alphabetic(ch) {
+				if !ch.is_ascii_lowercase() && ch.is_ascii_alphabetic() {
 					return Cow::Owned(owned(self, chars, (start, ch), false));
 				}
 			}
@@ -152,7 +198,7 @@ impl Casing for [u8] {
 			// result or extend with the upper case version if a lower case
 			// character is found.
 			for (i, ch) in chars {
-				if !is_ascii_lowercase(ch) && is_ascii_alphabetic(ch) {
+				if !ch.is_ascii_lowercase() && ch.is_ascii_alphabetic() {
 					if let Some(offset) = leftover.take() {
 						result.extend_from_slice(&this[offset .. i]);
 					}
@@ -173,190 +219,533 @@ impl Casing for [u8] {
 		}
 	}
 
-	fn camel(&self, separator: Separator<&[u8]>, mode: super::Camel, _locale: Locale) -> Cow<Self> {
-		let mut chars    = self.iter().cloned().enumerate();
-		let mut new_word = mode == super::Camel::Upper;
+	fn camel(&self, boundary: Boundary<u8>, mode: Camel, _locale: Locale) -> Cow<'_, Self> {
+		let (first, rest) = match mode {
+			Camel::Upper           => (Pattern::Capital, Pattern::Capital),
+			Camel::Lower           => (Pattern::Keep, Pattern::Capital),
+			Camel::UpperNormalized => (Pattern::Title, Pattern::Title),
+			Camel::LowerNormalized => (Pattern::Lower, Pattern::Title),
+		};
 
-		while let Some((start, ch)) = chars.next() {
-			if new_word && !is_ascii_uppercase(ch) && is_ascii_alphabetic(ch) {
-				return Cow::Owned(owned(self, separator, chars, (start, ch), new_word));
-			}
-			else if separator.iter().any(|&c| ch == c) {
-				return Cow::Owned(owned(self, separator, chars, (start, ch), true));
-			}
-			else {
-				new_word = false;
-			}
-		}
+		join(self, Words::new(self, boundary), None, first, rest)
+	}
 
-		return Cow::Borrowed(self);
+	fn separated(&self, boundary: Boundary<u8>, separator: Separator<u8>, _locale: Locale) -> Cow<'_, Self> {
+		join(self, Words::new(self, boundary), Some(separator.0), Pattern::Lower, Pattern::Lower)
+	}
 
-		#[inline(always)]
-		fn owned(this: &[u8], separator: Separator<&[u8]>, chars: Enumerate<Cloned<slice::Iter<u8>>>, (start, ch): (usize, u8), mut new_word: bool) -> Vec<u8> {
-			let mut result = Vec::with_capacity(this.len());
-			result.extend_from_slice(&this[.. start]);
+	fn title(&self, _locale: Locale) -> Cow<'_, Self> {
+		let mut result = Buffer::<[u8]>::new(self);
 
-			if separator.iter().all(|&c| ch != c) {
-				if new_word {
-					result.push(ch.to_ascii_uppercase());
+		// The start of the current word, if any, and the end of the last one.
+		let mut start = None;
+		let mut end   = 0;
+
+		for (i, &ch) in self.iter().enumerate() {
+			// Apostrophes don't break a word, `don't`.
+			let inside = ch.is_ascii_alphanumeric() || (start.is_some() && ch == b'\'');
+
+			match (start, inside) {
+				(None, true) => {
+					result.push_slice(&self[end .. i]);
+					start = Some(i);
 				}
-				else {
-					result.push(ch);
+
+				(Some(offset), false) => {
+					push(&mut result, &self[offset .. i], Pattern::Title, &Default::default());
+					start = None;
+					end   = i;
 				}
+
+				_ => ()
 			}
+		}
 
-			// The already properly cased starting offset, if any.
-			let mut leftover = None;
-			        new_word = separator.iter().any(|&c| ch == c);
+		match start {
+			Some(offset) => push(&mut result, &self[offset ..], Pattern::Title, &Default::default()),
+			None         => result.push_slice(&self[end ..]),
+		}
 
-			for (i, ch) in chars {
-				if new_word && !is_ascii_uppercase(ch) && is_ascii_alphabetic(ch) {
-					new_word = false;
+		result.finish()
+	}
 
-					if let Some(offset) = leftover.take() {
-						result.extend_from_slice(&this[offset .. i]);
-					}
+	fn train(&self, boundary: Boundary<u8>, separator: Separator<u8>, body: Body, _locale: Locale) -> Cow<'_, Self> {
+		let pattern = match body {
+			Body::Keep  => Pattern::Capital,
+			Body::Lower => Pattern::Title,
+		};
 
-					result.push(ch.to_ascii_uppercase());
-				}
-				else if separator.iter().any(|&c| ch == c) {
-					new_word = true;
+		join(self, Words::new(self, boundary), Some(separator.0), pattern, pattern)
+	}
 
-					if let Some(offset) = leftover.take() {
-						result.extend_from_slice(&this[offset .. i]);
-					}
-				}
-				else {
-					if leftover.is_none() {
-						leftover = Some(i);
+	fn header(&self, _locale: Locale) -> Cow<'_, Self> {
+		join(self, Words::new(self, Boundary::only(b"-")), Some(b'-'), Pattern::Capital, Pattern::Capital)
+	}
+
+	fn headline(&self, style: Style, locale: Locale) -> Cow<'_, Self> {
+		let mut result = Buffer::<[u8]>::new(self);
+
+		// Words in upper case are only kept as they are when there's some lower
+		// case around them, otherwise the whole text is shouting.
+		let shouting = !self.iter().any(u8::is_ascii_lowercase);
+		let mut rules = Rules::new(style, locale);
+
+		// Capitalized words can only be told apart as proper nouns when the
+		// text isn't shouting nor in title case already.
+		if let Rules::Sentence { ref mut proper } = rules {
+			let (words, capitalized) = self.split(u8::is_ascii_whitespace)
+				.filter_map(|word| word.iter().find(|ch| ch.is_ascii_alphanumeric()))
+				.fold((0, 0), |(words, capitalized), ch| (words + 1, capitalized + ch.is_ascii_uppercase() as usize));
+
+			*proper = !shouting && capitalized * 2 <= words;
+		}
+
+		let trimmed = self.trim_ascii_end();
+		let last    = trimmed.len() - trimmed.rsplit(u8::is_ascii_whitespace).next().map_or(0, <[u8]>::len);
+
+		let mut offset = 0;
+		let mut first  = true;
+
+		for piece in self.split_inclusive(u8::is_ascii_whitespace) {
+			let word = piece.trim_ascii_end();
+
+			if !word.is_empty() {
+				let parts = word.split(|&ch| ch == b'-').count();
+
+				// Each part of a compound is capitalized like a word of its own.
+				for (i, part) in word.split(|&ch| ch == b'-').enumerate() {
+					if i > 0 {
+						result.push(b'-');
 					}
 
-					new_word = false;
+					push_headline(&mut result, part, &rules, first && i == 0, offset == last && i == parts - 1, shouting);
 				}
-			}
 
-			// Append any leftover upper case characters.
-			if let Some(offset) = leftover.take() {
-				result.extend_from_slice(&this[offset ..]);
+				first = headline::is_subtitle(word);
 			}
 
-			result
+			result.push_slice(&piece[word.len() ..]);
+			offset += piece.len();
 		}
+
+		result.finish()
 	}
 
-	fn separated(&self, separator: Separator<u8>, _locale: Locale) -> Cow<Self> {
-		let mut chars = self.iter().cloned().enumerate();
+	fn sentence(&self, preserve: &[&str], locale: Locale) -> Cow<'_, Self> {
+		let mut result = Buffer::<[u8]>::new(self);
 
-		while let Some((start, ch)) = chars.next() {
-			if ch != separator.0 && !is_ascii_lowercase(ch) {
-				return Cow::Owned(owned(self, separator, chars, (start, ch)));
+		// Words in upper case are only kept as acronyms when there's some lower
+		// case around them, otherwise the whole text is shouting.
+		let shouting = !self.iter().any(u8::is_ascii_lowercase);
+		let mut start = true;
+
+		for piece in self.split_inclusive(u8::is_ascii_whitespace) {
+			let word = piece.trim_ascii_end();
+
+			// Each part of compounds and contractions is checked on its own.
+			for (i, part) in word.split_inclusive(|&ch| matches!(ch, b'-' | b'/' | b'\'')).enumerate() {
+				push_sentence(&mut result, part, preserve, start && i == 0, shouting, locale);
+			}
+
+			if !word.is_empty() {
+				start = headline::is_sentence_end(word);
 			}
+
+			result.push_slice(&piece[word.len() ..]);
 		}
 
-		return Cow::Borrowed(self);
+		result.finish()
+	}
 
-		#[inline(always)]
-		fn owned(this: &[u8], separator: Separator<u8>, chars: Enumerate<Cloned<slice::Iter<u8>>>, (start, ch): (usize, u8)) -> Vec<u8> {
-			let mut result = Vec::with_capacity(this.len());
-			result.extend_from_slice(&this[.. start]);
-			result.push(separator.0);
+	fn slug(&self, slug: Slug<'_, u8>) -> Cow<'_, Self> {
+		// Valid UTF-8 is transliterated like any string.
+		if let (Ok(string), true) = (str::from_utf8(self), slug.separator.is_ascii()) {
+			let slug = Slug { separator: char::from(slug.separator), length: slug.length, stopwords: slug.stopwords };
 
-			if is_ascii_alphabetic(ch) {
-				result.push(ch.to_ascii_lowercase());
-			}
+			return bytes(string.slug(slug));
+		}
 
-			// The already lower case starting offset, if any.
-			let mut leftover = None;
+		let mut result = Buffer::<[u8]>::new(self);
+		let mut length = 0;
+		let     limit  = slug.length.unwrap_or(usize::MAX);
 
-			for (i, ch) in chars {
-				if ch != separator.0 && !is_ascii_lowercase(ch) {
-					if let Some(offset) = leftover.take() {
-						result.extend_from_slice(&this[offset .. i]);
-					}
+		// Apostrophes and non ASCII bytes don't break a word, `don't`.
+		for word in self.split(|&ch| !(ch.is_ascii_alphanumeric() || ch == b'\'' || !ch.is_ascii())) {
+			let size = transliterate(word).count();
 
-					result.push(separator.0);
+			if size == 0 || slug.is_stopword(word) {
+				continue;
+			}
 
-					if is_ascii_alphabetic(ch) {
-						result.push(ch.to_ascii_lowercase());
-					}
-				}
-				else if leftover.is_none() {
-					leftover = Some(i);
+			let separator = (length != 0) as usize;
+
+			if length + separator + size > limit {
+				// A first word that's too long is cut, anything else is left out.
+				if length == 0 {
+					result.extend(transliterate(word).take(limit));
 				}
+
+				break;
 			}
 
-			// Append any leftover lower case characters.
-			if let Some(offset) = leftover.take() {
-				result.extend_from_slice(&this[offset ..]);
+			if separator != 0 {
+				result.push(slug.separator);
 			}
 
-			result
+			result.extend(transliterate(word));
+			length += separator + size;
 		}
+
+		result.finish()
 	}
 
-	fn header(&self, _locale: Locale) -> Cow<Self> {
-		let mut chars    = self.iter().cloned().enumerate();
-		let mut new_word = true;
+	fn to_case(&self, case: Case, _locale: Locale) -> Cow<'_, Self> {
+		let (first, rest, separator) = case.patterns();
 
-		while let Some((start, ch)) = chars.next() {
-			if new_word && !is_ascii_uppercase(ch) {
-				return Cow::Owned(owned(self, chars, (start, ch)));
+		join(self, Words::new(self, Default::default()), separator, first, rest)
+	}
+
+	fn is_case(&self, case: Case) -> bool {
+		let (first, rest, separator) = case.patterns();
+		let words = Words::new(self, Default::default());
+
+		// Nothing follows a case without any word.
+		words.clone().next().is_some() && check(self, words, separator, first, rest)
+	}
+
+	fn detect_case(&self) -> Cases {
+		Case::ALL.iter().cloned().filter(|&case| self.is_case(case)).collect()
+	}
+
+	fn words<'a>(&'a self, boundary: Boundary<'a, Self::Character>) -> Words<'a, Self> {
+		Words::new(self, boundary)
+	}
+}
+
+impl<'a> Converter<'a, u8> {
+	/// Converts the string avoiding allocations if nothing would change.
+	pub fn convert<'b>(&self, string: &'b [u8]) -> Cow<'b, [u8]> {
+		let (first, rest, separator) = self.patterns();
+
+		join(string, Words::new(string, self.boundary), separator, first, rest)
+	}
+}
+
+impl<'a> Slug<'a, u8> {
+	/// Whether the word is a stop word, compared after transliterating both.
+	#[inline(always)]
+	fn is_stopword(&self, word: &[u8]) -> bool {
+		self.stopwords.iter().any(|stop| transliterate(stop.as_bytes()).eq(transliterate(word)))
+	}
+}
+
+impl<'a> Words<'a, [u8]> {
+	#[inline(always)]
+	fn is_separator(&self, ch: u8) -> bool {
+		// Non ASCII bytes are considered part of words.
+		self.boundary.separators.contains(&ch) || match self.boundary.symbols {
+			Symbols::Separate             => ch.is_ascii() && !ch.is_ascii_alphanumeric(),
+			Symbols::Drop | Symbols::Word => ch.is_ascii_whitespace(),
+			Symbols::Attach               => false,
+		}
+	}
+
+	#[inline(always)]
+	fn is_symbol(&self, ch: u8) -> bool {
+		matches!(self.boundary.symbols, Symbols::Drop | Symbols::Word) && is_symbol(ch)
+	}
+}
+
+impl<'a> Iterator for Words<'a, [u8]> {
+	type Item = Word<'a, [u8]>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some(word) = self.acronym() {
+			return Some(word);
+		}
+
+		let input     = &self.input[self.offset ..];
+		let mut chars = input.iter().cloned().enumerate().peekable();
+
+		// Skip any leading separator.
+		let (start, mut previous) = loop {
+			match chars.next() {
+				Some((_, ch)) if self.is_separator(ch) => (),
+				Some((_, ch)) if self.boundary.symbols == Symbols::Drop && self.is_symbol(ch) => (),
+				Some(found) => break found,
+
+				None => {
+					self.offset = self.input.len();
+					return None;
+				}
 			}
-			else if ch == b'-' {
-				new_word = true;
+		};
+
+		let mut end     = input.len();
+		let mut acronym = false;
+		let mut numeric = previous.is_ascii_digit();
+		let mut upper   = previous.is_ascii_uppercase() as usize;
+		let mut lower   = previous.is_ascii_lowercase() as usize;
+		let     title   = previous.is_ascii_uppercase();
+		let     symbol  = self.is_symbol(previous);
+
+		while let Some((i, ch)) = chars.next() {
+			if self.is_separator(ch) {
+				end = i;
+				break;
 			}
-			else {
-				new_word = false;
+
+			// A run of symbols as a word of its own, `(USD)`.
+			if self.boundary.symbols == Symbols::Word && self.is_symbol(ch) != symbol {
+				end = i;
+				break;
 			}
-		}
 
-		return Cow::Borrowed(self);
+			// A letter followed by a digit or the other way around, `version2`.
+			if self.boundary.digit && ((previous.is_ascii_alphabetic() && ch.is_ascii_digit()) || (previous.is_ascii_digit() && ch.is_ascii_alphabetic())) {
+				end = i;
+				break;
+			}
 
-		#[inline(always)]
-		fn owned(this: &[u8], chars: Enumerate<Cloned<slice::Iter<u8>>>, (start, ch): (usize, u8)) -> Vec<u8> {
-			let mut result = Vec::with_capacity(this.len());
-			result.extend_from_slice(&this[.. start]);
-			result.push(ch.to_ascii_uppercase());
+			// A lower case character or digit followed by an upper case one, `fooBar`.
+			if self.boundary.case && ch.is_ascii_uppercase() && (previous.is_ascii_lowercase() || previous.is_ascii_digit()) {
+				end = i;
+				break;
+			}
 
-			// The already properly cased starting offset, if any.
-			let mut leftover = None;
-			let mut new_word = false;
+			// The last upper case character before a lower case one, `HTTPServer`.
+			if self.boundary.acronym && ch.is_ascii_uppercase() && previous.is_ascii_uppercase() && chars.peek().is_some_and(|&(_, next)| next.is_ascii_lowercase()) {
+				end     = i;
+				acronym = true;
+				break;
+			}
 
-			for (i, ch) in chars {
-				if new_word && !is_ascii_uppercase(ch) {
-					new_word = false;
+			numeric  = numeric && ch.is_ascii_digit();
+			upper   += ch.is_ascii_uppercase() as usize;
+			lower   += ch.is_ascii_lowercase() as usize;
+			previous = ch;
+		}
 
-					if let Some(offset) = leftover.take() {
-						result.extend_from_slice(&this[offset .. i]);
-					}
+		let mut kind = kind(acronym, numeric, title, upper, lower);
 
-					result.push(ch.to_ascii_uppercase());
-				}
-				else {
-					if ch == b'-' {
-						new_word = true;
-					}
+		// Split runs of upper case characters made of known acronyms, `HTTPURL`.
+		if kind == Kind::Upper || kind == Kind::Acronym {
+			let acronyms = &self.boundary.acronyms;
 
-					if leftover.is_none() {
-						leftover = Some(i);
-					}
-				}
+			if acronyms.split(&input[start .. end], self.offset + start, &mut self.acronyms) {
+				end  = self.acronyms.pop().map_or(end, |end| end - self.offset);
+				kind = Kind::Acronym;
 			}
+			else if acronyms.contains(&input[start .. end]) {
+				kind = Kind::Acronym;
+			}
+		}
 
-			// Append any leftover upper case characters.
-			if let Some(offset) = leftover.take() {
-				result.extend_from_slice(&this[offset ..]);
+		let offset = self.offset + start;
+		self.offset += end;
+
+		Some(Word::new(offset, &input[start .. end], kind))
+	}
+}
+
+#[inline(always)]
+fn kind(acronym: bool, numeric: bool, title: bool, upper: usize, lower: usize) -> Kind {
+	if numeric {
+		Kind::Number
+	}
+	else if acronym && lower == 0 {
+		Kind::Acronym
+	}
+	else if upper == 0 {
+		Kind::Lower
+	}
+	else if lower == 0 {
+		Kind::Upper
+	}
+	else if upper == 1 && title {
+		Kind::Title
+	}
+	else {
+		Kind::Mixed
+	}
+}
+
+/// Joins the words back together using the given separator, casing the first
+/// and the rest of the words following the given patterns.
+#[inline(always)]
+fn join<'a>(this: &'a [u8], words: Words<'_, [u8]>, separator: Option<u8>, first: Pattern, rest: Pattern) -> Cow<'a, [u8]> {
+	let mut result = Buffer::<[u8]>::new(this);
+	write(&mut result, words, separator, first, rest);
+	result.finish()
+}
+
+/// Checks if joining the words back together would give back the same string.
+#[inline(always)]
+fn check(this: &[u8], words: Words<'_, [u8]>, separator: Option<u8>, first: Pattern, rest: Pattern) -> bool {
+	let mut result = Buffer::<[u8]>::check(this);
+	write(&mut result, words, separator, first, rest);
+	result.matches()
+}
+
+#[inline(always)]
+fn write(result: &mut Buffer<[u8]>, words: Words<'_, [u8]>, separator: Option<u8>, first: Pattern, rest: Pattern) {
+	let acronyms = words.boundary.acronyms;
+	let symbols  = words.boundary.symbols;
+
+	for (i, word) in words.enumerate() {
+		if result.is_mismatched() {
+			break;
+		}
+
+		let pattern = if i == 0 { first } else { rest };
+
+		if i != 0 {
+			if let Some(separator) = separator {
+				result.push(separator);
 			}
+		}
 
-			result
+		if symbols == Symbols::Drop {
+			push_dropping(result, word.text(), pattern, &acronyms);
+		}
+		else {
+			push(result, word.text(), pattern, &acronyms);
 		}
 	}
 }
 
+/// Pushes the word into the buffer cased following the pattern, dropping any
+/// symbol within it.
+#[inline(always)]
+fn push_dropping(result: &mut Buffer<[u8]>, word: &[u8], pattern: Pattern, acronyms: &Acronyms) {
+	let mut pattern = pattern;
+
+	for part in word.split(|&ch| is_symbol(ch)).filter(|part| !part.is_empty()) {
+		push(result, part, pattern, acronyms);
+		pattern = pattern.rest();
+	}
+}
+
+/// Pushes a word of a headline, following the rules.
+#[inline(always)]
+fn push_headline(result: &mut Buffer<[u8]>, word: &[u8], rules: &Rules, first: bool, last: bool, shouting: bool) {
+	// Any punctuation around the word is left as it is.
+	let start = word.iter().position(u8::is_ascii_alphanumeric).unwrap_or(word.len());
+	let end   = word.iter().rposition(u8::is_ascii_alphanumeric).map_or(start, |i| i + 1);
+	let core  = &word[start .. end];
+
+	let intentional = if shouting {
+		headline::is_roman(core)
+	}
+	else {
+		core.iter().skip(1).any(u8::is_ascii_uppercase)
+	};
+
+	let capitalized = core.first().is_some_and(u8::is_ascii_uppercase);
+	let pattern     = rules.pattern(core, first, last, intentional, capitalized);
+
+	result.push_slice(&word[.. start]);
+	push(result, &word[start ..], pattern, &Default::default());
+}
+
+/// Pushes a word of a sentence, capitalized if it starts the sentence.
+#[inline(always)]
+fn push_sentence(result: &mut Buffer<[u8]>, word: &[u8], preserve: &[&str], first: bool, shouting: bool, locale: Locale) {
+	// Any punctuation around the word is left as it is.
+	let start = word.iter().position(u8::is_ascii_alphanumeric).unwrap_or(word.len());
+	let end   = word.iter().rposition(u8::is_ascii_alphanumeric).map_or(start, |i| i + 1);
+	let core  = &word[start .. end];
+
+	result.push_slice(&word[.. start]);
+
+	if let Some(preserved) = headline::find(preserve, core) {
+		result.push_slice(preserved.as_bytes());
+	}
+	else {
+		// Acronyms and words with intentional mixed case, `MySQL`, and the
+		// English pronoun, on its own or in contractions, `I'm`.
+		let keep = (locale == Locale::None && core == b"I") || (!shouting && core.iter().skip(1).any(u8::is_ascii_uppercase));
+
+		let pattern = if keep {
+			Pattern::Keep
+		}
+		else if first {
+			Pattern::Title
+		}
+		else {
+			Pattern::Lower
+		};
+
+		push(result, core, pattern, &Default::default());
+	}
+
+	result.push_slice(&word[end ..]);
+}
+
+/// The word in lower case ASCII letters and digits, anything else is dropped.
+#[inline(always)]
+fn transliterate(word: &[u8]) -> impl Iterator<Item = u8> + '_ {
+	word.iter().filter(|ch| ch.is_ascii_alphanumeric()).map(u8::to_ascii_lowercase)
+}
+
+/// The bytes of a string produced from valid UTF-8.
+#[inline(always)]
+fn bytes(string: Cow<'_, str>) -> Cow<'_, [u8]> {
+	match string {
+		Cow::Borrowed(string) => Cow::Borrowed(string.as_bytes()),
+		Cow::Owned(string)    => Cow::Owned(string.into_bytes()),
+	}
+}
+
+/// Whether the byte is ASCII punctuation or a symbol, non ASCII bytes are
+/// considered part of words.
+#[inline(always)]
+fn is_symbol(ch: u8) -> bool {
+	ch.is_ascii() && !ch.is_ascii_alphanumeric() && !ch.is_ascii_whitespace()
+}
+
+/// Pushes the word into the buffer cased following the pattern.
+#[inline(always)]
+fn push(result: &mut Buffer<[u8]>, word: &[u8], pattern: Pattern, acronyms: &Acronyms) {
+	match pattern.acronym(acronyms, word, word.len()) {
+		Pattern::Keep =>
+			result.push_slice(word),
+
+		Pattern::Lower =>
+			for &ch in word {
+				result.push(ch.to_ascii_lowercase());
+			},
+
+		Pattern::Upper =>
+			for &ch in word {
+				result.push(ch.to_ascii_uppercase());
+			},
+
+		Pattern::Capital =>
+			if let Some((&ch, rest)) = word.split_first() {
+				result.push(ch.to_ascii_uppercase());
+				result.push_slice(rest);
+			},
+
+		Pattern::Title =>
+			if let Some((&ch, rest)) = word.split_first() {
+				result.push(ch.to_ascii_uppercase());
+
+				for &ch in rest {
+					result.push(ch.to_ascii_lowercase());
+				}
+			},
+	}
+}
+
 #[cfg(test)]
 mod test {
 	use std::borrow::Cow;
-	use {Casing, Camel, Separator};
+	use std::cmp::Ordering;
+	use std::hash::Hasher;
+	use std::collections::hash_map::DefaultHasher;
+	use std::time::{Duration, Instant};
+	use {Casing, Camel, Capital, FoldMode, Case, Body, Locale, Style, Separator, Boundary, Symbols, Slug, Acronyms, Initialism, Kind};
 
 	macro_rules! assert_owned {
 		($body:expr) => (
@@ -415,21 +804,81 @@ mod test {
 		assert_owned!(b"FOO".lower(Default::default()));
 	}
 
+	#[test]
+	fn fold() {
+		assert_eq!(b"fish".to_vec(), b"FiSH".fold(FoldMode::Full, Default::default()).into_owned());
+		assert_eq!("ıstanbul".as_bytes().to_vec(), b"ISTANBUL".fold(FoldMode::Simple, Locale::Turkish).into_owned());
+		assert_eq!(b"strasse".to_vec(), "Straße".as_bytes().fold(FoldMode::Full, Default::default()).into_owned());
+		assert_eq!(b"fi\xffsh".to_vec(), b"FI\xffSH".fold(FoldMode::Full, Default::default()).into_owned());
+		assert_eq!(["fı".as_bytes(), b"\xffss"].concat(), b"FI\xff\xc3\x9f".fold(FoldMode::Full, Locale::Turkish).into_owned());
+	}
+
+	#[test]
+	fn fold_allocation() {
+		assert_borrowed!(b"fish".fold(FoldMode::Full, Default::default()));
+		assert_owned!(b"Fish".fold(FoldMode::Full, Default::default()));
+	}
+
+	#[test]
+	fn ignore_case() {
+		fn hash(string: &[u8], locale: Locale) -> u64 {
+			let mut state = DefaultHasher::new();
+			string.hash_folded(&mut state, locale);
+			state.finish()
+		}
+
+		assert!(b"Content-Type".eq_ignore_case(b"content-type", Default::default()));
+		assert!(!b"Content-Type".eq_ignore_case(b"content-typ", Default::default()));
+		assert!("Straße".as_bytes().eq_ignore_case(b"STRASSE", Default::default()));
+		assert!(b"A\xff".eq_ignore_case(b"a\xff", Default::default()));
+		assert!(!b"KIT".eq_ignore_case(b"kit", Locale::Turkish));
+		assert!(b"KIT".eq_ignore_case("kıt".as_bytes(), Locale::Turkish));
+
+		assert_eq!(Ordering::Equal, b"Content-Type".cmp_ignore_case(b"CONTENT-TYPE", Default::default()));
+		assert_eq!(Ordering::Less, b"apple".cmp_ignore_case(b"Banana", Default::default()));
+		assert_eq!(Ordering::Greater, b"Zebra".cmp_ignore_case(b"apple", Default::default()));
+		assert_eq!(Ordering::Equal, "Straße".as_bytes().cmp_ignore_case(b"STRASSE", Default::default()));
+
+		// Invalid bytes are compared as they are, so the order is the same
+		// whether the rest is valid UTF-8 or not.
+		let kelvin = "\u{212a}".as_bytes();
+
+		assert!(kelvin.eq_ignore_case(b"k", Default::default()));
+		assert!(b"K\xff".eq_ignore_case(&[kelvin, b"\xff"].concat(), Default::default()));
+		assert_eq!(Ordering::Less, kelvin.cmp_ignore_case(b"k\xff", Default::default()));
+		assert_eq!(Ordering::Less, b"k".cmp_ignore_case(b"k\xff", Default::default()));
+
+		assert_eq!(hash(b"Content-Type", Default::default()), hash(b"CONTENT-TYPE", Default::default()));
+		assert_eq!(hash(&b"A".repeat(100), Default::default()), hash(&b"a".repeat(100), Default::default()));
+		assert_eq!(hash(b"A\xff", Default::default()), hash(b"a\xff", Default::default()));
+		assert_eq!(hash(&[kelvin, b"\xff"].concat(), Default::default()), hash(b"k\xff", Default::default()));
+		assert_eq!(hash(b"KIT", Locale::Turkish), hash("kıt".as_bytes(), Locale::Turkish));
+		assert_ne!(hash(b"ab", Default::default()), hash(b"ba", Default::default()));
+	}
+
 	#[test]
 	fn capitalized() {
-		assert_eq!(b"Foo".to_vec(), b"Foo".capitalized(Default::default()).into_owned());
-		assert_eq!(b"Foo".to_vec(), b"FoO".capitalized(Default::default()).into_owned());
-		assert_eq!(b"Foo".to_vec(), b"foo".capitalized(Default::default()).into_owned());
-		assert_eq!(b"Foo".to_vec(), b"foO".capitalized(Default::default()).into_owned());
+		assert_eq!(b"Foo".to_vec(), b"Foo".capitalized(Default::default(), Default::default()).into_owned());
+		assert_eq!(b"Foo".to_vec(), b"FoO".capitalized(Default::default(), Default::default()).into_owned());
+		assert_eq!(b"Foo".to_vec(), b"foo".capitalized(Default::default(), Default::default()).into_owned());
+		assert_eq!(b"Foo".to_vec(), b"foO".capitalized(Default::default(), Default::default()).into_owned());
+	}
+
+	#[test]
+	fn capitalized_letter() {
+		assert_eq!(b"'Twas".to_vec(), b"'twas".capitalized(Capital::Letter, Default::default()).into_owned());
+		assert_eq!(b"\"Foo bar\"".to_vec(), b"\"FOO BAR\"".capitalized(Capital::Letter, Default::default()).into_owned());
+		assert_eq!(b"1st".to_vec(), b"1st".capitalized(Capital::Letter, Default::default()).into_owned());
+		assert_eq!(b"'twas".to_vec(), b"'twas".capitalized(Capital::First, Default::default()).into_owned());
 	}
 
 	#[test]
 	fn capitalized_allocation() {
-		assert_borrowed!(b"Foo".capitalized(Default::default()));
-		assert_borrowed!("Foo-foo-æßð".as_bytes().capitalized(Default::default()));
+		assert_borrowed!(b"Foo".capitalized(Default::default(), Default::default()));
+		assert_borrowed!("Foo-foo-æßð".as_bytes().capitalized(Default::default(), Default::default()));
 
-		assert_owned!(b"fOOOOO".capitalized(Default::default()));
-		assert_owned!(b"REEEeE".capitalized(Default::default()));
+		assert_owned!(b"fOOOOO".capitalized(Default::default(), Default::default()));
+		assert_owned!(b"REEEeE".capitalized(Default::default(), Default::default()));
 	}
 
 	#[test]
@@ -445,27 +894,104 @@ mod test {
 		assert_eq!(b"foo".to_vec(), b"foo".camel(Default::default(), Camel::Lower, Default::default()).into_owned());
 	}
 
+	#[test]
+	fn camel_normalized() {
+		assert_eq!(b"FooBar".to_vec(), b"FOO_BAR".camel(Default::default(), Camel::UpperNormalized, Default::default()).into_owned());
+		assert_eq!(b"fooBAR".to_vec(), b"foo_BAR".camel(Default::default(), Camel::Lower, Default::default()).into_owned());
+		assert_eq!(b"fooBar".to_vec(), b"foo_BAR".camel(Default::default(), Camel::LowerNormalized, Default::default()).into_owned());
+		assert_eq!(b"maxPathLen".to_vec(), b"MAX_PATH_LEN".camel(Default::default(), Camel::LowerNormalized, Default::default()).into_owned());
+	}
+
+	#[test]
+	fn camel_acronyms() {
+		let title = Boundary { acronyms: Acronyms::new(Acronyms::COMMON, Initialism::Title), .. Default::default() };
+		let upper = Boundary { acronyms: Acronyms::new(Acronyms::COMMON, Initialism::Upper), .. Default::default() };
+		let short = Boundary { acronyms: Acronyms::new(Acronyms::COMMON, Initialism::Short), .. Default::default() };
+
+		assert_eq!(b"HttpUrl".to_vec(), b"http_url".camel(title, Camel::Upper, Default::default()).into_owned());
+		assert_eq!(b"HttpUrl".to_vec(), b"HTTPURL".camel(title, Camel::Upper, Default::default()).into_owned());
+		assert_eq!(b"HTTPURL".to_vec(), b"http_url".camel(upper, Camel::Upper, Default::default()).into_owned());
+		assert_eq!(b"httpURL".to_vec(), b"http_url".camel(upper, Camel::Lower, Default::default()).into_owned());
+		assert_eq!(b"IOStream".to_vec(), b"io_stream".camel(short, Camel::Upper, Default::default()).into_owned());
+		assert_eq!(b"HttpUrl".to_vec(), b"http_url".camel(short, Camel::Upper, Default::default()).into_owned());
+	}
+
+	#[test]
+	fn camel_acronyms_long() {
+		let upper = Boundary { acronyms: Acronyms::new(Acronyms::COMMON, Initialism::Upper), .. Default::default() };
+		let input = b"ID".repeat(10_000);
+		let start = Instant::now();
+
+		assert_eq!(input, b"ID_".repeat(10_000).camel(upper, Camel::Upper, Default::default()).into_owned());
+		assert_eq!(input, input.camel(upper, Camel::Upper, Default::default()).into_owned());
+		assert_eq!(10_000, input.words(upper).count());
+		assert!(start.elapsed() < Duration::from_secs(2), "{:?}", start.elapsed());
+	}
+
 	#[test]
 	fn camel_allocation() {
 		assert_borrowed!(b"FooBar".camel(Default::default(), Camel::Upper, Default::default()));
 		assert_borrowed!(b"fooBar".camel(Default::default(), Camel::Lower, Default::default()));
+		assert_borrowed!(b"fooBar".camel(Default::default(), Camel::LowerNormalized, Default::default()));
+
+		assert_owned!(b"FOO_BAR".camel(Default::default(), Camel::UpperNormalized, Default::default()));
 	}
 
 	#[test]
 	fn separated() {
-		assert_eq!(b"foo_bar".to_vec(), b"foo_bar".separated(Separator(b'_'), Default::default()).into_owned());
-		assert_eq!(b"foo-bar-baz".to_vec(), b"foo_bar_baz".separated(Separator(b'-'), Default::default()).into_owned());
+		assert_eq!(b"foo_bar".to_vec(), b"foo_bar".separated(Default::default(), Separator(b'_'), Default::default()).into_owned());
+		assert_eq!(b"foo-bar-baz".to_vec(), b"foo_bar_baz".separated(Default::default(), Separator(b'-'), Default::default()).into_owned());
+		assert_eq!(b"foo_bar".to_vec(), b"FooBar".separated(Default::default(), Separator(b'_'), Default::default()).into_owned());
+		assert_eq!(b"xml_http_request".to_vec(), b"XMLHttpRequest".separated(Default::default(), Separator(b'_'), Default::default()).into_owned());
+		assert_eq!(b"version2_update".to_vec(), b"version2Update".separated(Default::default(), Separator(b'_'), Default::default()).into_owned());
+		assert_eq!(b"version_2_update".to_vec(), b"version2Update".separated(Boundary { digit: true, .. Default::default() }, Separator(b'_'), Default::default()).into_owned());
+		assert_eq!(b"v2_api".to_vec(), b"v2Api".separated(Default::default(), Separator(b'_'), Default::default()).into_owned());
+		assert_eq!(b"http_server".to_vec(), b"HTTPServer".separated(Default::default(), Separator(b'_'), Default::default()).into_owned());
 	}
 
 	#[test]
 	fn separated_allocation() {
-		assert_borrowed!(b"foo_bar".separated(Separator(b'_'), Default::default()));
-		assert_borrowed!(b"foo-bar".separated(Separator(b'-'), Default::default()));
-		assert_borrowed!(b"foo@bar".separated(Separator(b'@'), Default::default()));
+		assert_borrowed!(b"foo_bar".separated(Default::default(), Separator(b'_'), Default::default()));
+		assert_borrowed!(b"foo-bar".separated(Default::default(), Separator(b'-'), Default::default()));
+		assert_borrowed!(b"foo@bar".separated(Default::default(), Separator(b'@'), Default::default()));
+
+		assert_owned!(b"foo_bar".separated(Default::default(), Separator(b'@'), Default::default()));
+		assert_owned!(b"foo-bar".separated(Default::default(), Separator(b'_'), Default::default()));
+		assert_owned!(b"foo@bar".separated(Default::default(), Separator(b'-'), Default::default()));
+	}
+
+	#[test]
+	fn symbols() {
+		let boundary = |symbols| Boundary { symbols, .. Default::default() };
+
+		assert_eq!(b"total_price_usd".to_vec(), b"Total Price (USD)".separated(boundary(Symbols::Separate), Separator(b'_'), Default::default()).into_owned());
+		assert_eq!(b"dont_stop".to_vec(), b"Don't stop!".separated(boundary(Symbols::Drop), Separator(b'_'), Default::default()).into_owned());
+		assert_eq!(b"totalPriceUsd".to_vec(), b"Total Price (USD)".camel(boundary(Symbols::Drop), Camel::LowerNormalized, Default::default()).into_owned());
+		assert_eq!(b"a.b c_d".to_vec(), b"a.b c-d".separated(boundary(Symbols::Attach), Separator(b'_'), Default::default()).into_owned());
+		assert_eq!(b"total_price_(_usd_)".to_vec(), b"Total Price (USD)".separated(boundary(Symbols::Word), Separator(b'_'), Default::default()).into_owned());
+	}
+
+	#[test]
+	fn symbols_allocation() {
+		let boundary = |symbols| Boundary { symbols, .. Default::default() };
 
-		assert_owned!(b"foo_bar".separated(Separator(b'@'), Default::default()));
-		assert_owned!(b"foo-bar".separated(Separator(b'_'), Default::default()));
-		assert_owned!(b"foo@bar".separated(Separator(b'-'), Default::default()));
+		assert_borrowed!(b"total_price".separated(boundary(Symbols::Drop), Separator(b'_'), Default::default()));
+		assert_borrowed!(b"dont_stop!".separated(boundary(Symbols::Drop), Separator(b'_'), Default::default()));
+
+		assert_owned!(b"don't_stop".separated(boundary(Symbols::Drop), Separator(b'_'), Default::default()));
+	}
+
+	#[test]
+	fn title() {
+		assert_eq!(b"Large Red Mug".to_vec(), b"LARGE RED MUG".title(Default::default()).into_owned());
+		assert_eq!(b"Hello,  World!\tFoo-Bar_Baz".to_vec(), b"hello,  WORLD!\tfoo-bar_baz".title(Default::default()).into_owned());
+		assert_eq!(b"Don't Stop".to_vec(), b"don't stop".title(Default::default()).into_owned());
+	}
+
+	#[test]
+	fn title_allocation() {
+		assert_borrowed!(b"Large Red Mug".title(Default::default()));
+		assert_owned!(b"Large red Mug".title(Default::default()));
 	}
 
 	#[test]
@@ -490,6 +1016,198 @@ mod test {
 		assert_owned!(b"foo-bar".header(Default::default()));
 		assert_owned!(b"foo-bar-baz".header(Default::default()));
 	}
+
+	#[test]
+	fn train() {
+		let upper = Boundary { acronyms: Acronyms::new(&["MIME"], Initialism::Upper), .. Default::default() };
+
+		assert_eq!(b"Created-At".to_vec(), b"createdAt".train(Default::default(), Separator(b'-'), Body::Keep, Default::default()).into_owned());
+		assert_eq!(b"Created At".to_vec(), b"createdAt".train(Default::default(), Separator(b' '), Body::Keep, Default::default()).into_owned());
+		assert_eq!(b"Mime-Type".to_vec(), b"MIME_TYPE".train(Default::default(), Separator(b'-'), Body::Lower, Default::default()).into_owned());
+		assert_eq!(b"MIME-Type".to_vec(), b"mime_TYPE".train(upper, Separator(b'-'), Body::Lower, Default::default()).into_owned());
+	}
+
+	#[test]
+	fn train_allocation() {
+		assert_borrowed!(b"Created-At".train(Default::default(), Separator(b'-'), Body::Keep, Default::default()));
+
+		assert_owned!(b"createdAt".train(Default::default(), Separator(b'-'), Body::Keep, Default::default()));
+	}
+
+	#[test]
+	fn headline() {
+		assert_eq!(b"The Lord of the Rings".to_vec(), b"THE LORD OF THE RINGS".headline(Style::Chicago, Default::default()).into_owned());
+		assert_eq!(b"Star Wars: The Empire Strikes Back".to_vec(), b"star wars: the empire strikes back".headline(Style::Mla, Default::default()).into_owned());
+		assert_eq!(b"Self-Driving Cars in the City".to_vec(), b"self-driving cars in the city".headline(Style::Ap, Default::default()).into_owned());
+		assert_eq!(b"Why the iPhone Beat NASA".to_vec(), b"why the iPhone beat NASA".headline(Style::Apa, Default::default()).into_owned());
+		assert_eq!(b"Henry VIII".to_vec(), b"HENRY VIII".headline(Style::Chicago, Default::default()).into_owned());
+	}
+
+	#[test]
+	fn headline_locale() {
+		assert_eq!(b"La casa de papel".to_vec(), b"LA CASA DE PAPEL".headline(Default::default(), Locale::Spanish).into_owned());
+		assert_eq!(b"Le petit prince a Paris".to_vec(), b"le petit prince a Paris".headline(Default::default(), Locale::French).into_owned());
+		assert_eq!(b"Il Nome della Rosa".to_vec(), b"il nome della rosa".headline(Default::default(), Locale::Italian).into_owned());
+	}
+
+	#[test]
+	fn headline_allocation() {
+		assert_borrowed!(b"The Lord of the Rings".headline(Style::Chicago, Default::default()));
+		assert_owned!(b"the Lord of the Rings".headline(Style::Chicago, Default::default()));
+	}
+
+	#[test]
+	fn sentence() {
+		assert_eq!(b"Getting started with GitHub".to_vec(), b"Getting Started With Github".sentence(&["GitHub"], Default::default()).into_owned());
+		assert_eq!(b"Use the API. Then ask Dr. Who, e.g. the doctor.".to_vec(), b"use the API. then ask Dr. Who, e.g. The Doctor.".sentence(&["Dr", "Who"], Default::default()).into_owned());
+		assert_eq!(b"Stop! Now".to_vec(), b"STOP! NOW".sentence(&[], Default::default()).into_owned());
+		assert_eq!(b"He said no. Try again.".to_vec(), b"He said no. Try again.".sentence(&[], Default::default()).into_owned());
+		assert_eq!(b"How I built this".to_vec(), b"How I Built This".sentence(&[], Default::default()).into_owned());
+		assert_eq!(b"I'm sure I've seen it".to_vec(), b"I'M SURE I'VE SEEN IT".sentence(&[], Default::default()).into_owned());
+	}
+
+	#[test]
+	fn sentence_allocation() {
+		assert_borrowed!(b"Getting started with the API.".sentence(&[], Default::default()));
+		assert_owned!(b"Getting Started".sentence(&[], Default::default()));
+	}
+
+	#[test]
+	fn slug() {
+		assert_eq!(b"dont-stop-me-now".to_vec(), b"Don't stop me now!".slug(Default::default()).into_owned());
+		assert_eq!(b"total_price".to_vec(), b"  Total Price (USD)  ".slug(Slug { separator: b'_', stopwords: &["usd"], .. Default::default() }).into_owned());
+		assert_eq!(b"total".to_vec(), b"Total Price (USD)".slug(Slug { length: Some(10), .. Default::default() }).into_owned());
+		assert_eq!(b"creme-brulee".to_vec(), "Crème Brûlée".as_bytes().slug(Default::default()).into_owned());
+		assert_eq!(b"crme-brle".to_vec(), b"Cr\xe8me Br\xfbl\xe9e".slug(Default::default()).into_owned());
+		assert_eq!(b"crme\xffbrle".to_vec(), b"Cr\xe8me Br\xfbl\xe9e".slug(Slug { separator: 0xff, .. Default::default() }).into_owned());
+	}
+
+	#[test]
+	fn slug_allocation() {
+		assert_borrowed!(b"creme-brulee-a-la-mode".slug(Default::default()));
+		assert_owned!(b"Creme Brulee".slug(Default::default()));
+	}
+
+	#[test]
+	fn to_case() {
+		assert_eq!(b"foo_bar_baz".to_vec(), b"fooBarBaz".to_case(Case::Snake, Default::default()).into_owned());
+		assert_eq!(b"foo-bar-baz".to_vec(), b"FooBarBaz".to_case(Case::Kebab, Default::default()).into_owned());
+		assert_eq!(b"FOO_BAR_BAZ".to_vec(), b"foo-bar-baz".to_case(Case::ScreamingSnake, Default::default()).into_owned());
+		assert_eq!(b"fooBarBaz".to_vec(), b"FOO_BAR_BAZ".to_case(Case::Camel, Default::default()).into_owned());
+		assert_eq!(b"FooBarBaz".to_vec(), b"foo bar baz".to_case(Case::Pascal, Default::default()).into_owned());
+		assert_eq!(b"Foo-Bar-Baz".to_vec(), b"foo_BAR_baz".to_case(Case::Train, Default::default()).into_owned());
+		assert_eq!(b"MIME-Type".to_vec(), b"MIME_type".to_case(Case::Header, Default::default()).into_owned());
+		assert_eq!(b"Foo bar baz".to_vec(), b"fooBarBaz".to_case(Case::Sentence, Default::default()).into_owned());
+		assert_eq!(b"foo/bar/baz".to_vec(), b"fooBarBaz".to_case(Case::Path, Default::default()).into_owned());
+		assert_eq!(b"foo.bar.baz".to_vec(), b"foo_bar_baz".to_case(Case::Dot, Default::default()).into_owned());
+		assert_eq!(b"FOOBARBAZ".to_vec(), b"fooBarBaz".to_case(Case::UpperFlat, Default::default()).into_owned());
+		assert_eq!(b"FOO-BAR-BAZ".to_vec(), b"fooBarBaz".to_case(Case::Cobol, Default::default()).into_owned());
+		assert_eq!(b"Foo_Bar_Baz".to_vec(), b"FOO_BAR_BAZ".to_case(Case::Ada, Default::default()).into_owned());
+	}
+
+	#[test]
+	fn to_case_allocation() {
+		assert_borrowed!(b"foo_bar".to_case(Case::Snake, Default::default()));
+		assert_borrowed!(b"fooBar".to_case(Case::Camel, Default::default()));
+		assert_borrowed!(b"FOO-BAR".to_case(Case::Cobol, Default::default()));
+		assert_borrowed!(b"Foo_Bar".to_case(Case::Ada, Default::default()));
+		assert_borrowed!(b"foo.bar".to_case(Case::Dot, Default::default()));
+		assert_borrowed!(b"FOOBAR".to_case(Case::UpperFlat, Default::default()));
+
+		assert_owned!(b"fooBar".to_case(Case::Snake, Default::default()));
+		assert_owned!(b"FOO_BAR".to_case(Case::Cobol, Default::default()));
+		assert_owned!(b"FOO_BAR".to_case(Case::Pascal, Default::default()));
+	}
+
+	#[test]
+	fn is_case() {
+		assert!(b"foo_bar".is_snake());
+		assert!(b"foo-bar".is_kebab());
+		assert!(b"FOO_BAR".is_screaming_snake());
+		assert!(b"fooBar".is_camel());
+		assert!(b"FooBar".is_pascal());
+
+		assert!(!b"foo__bar".is_snake());
+		assert!(!b"foo_Bar".is_snake());
+		assert!(!b"FooBar".is_camel());
+		assert!(!b"fooBar".is_pascal());
+	}
+
+	#[test]
+	fn detect_case() {
+		let cases = b"foo".detect_case();
+		assert!(cases.contains(Case::Snake));
+		assert!(cases.contains(Case::Camel));
+		assert!(cases.contains(Case::Flat));
+		assert!(!cases.contains(Case::Pascal));
+
+		assert_eq!(vec![Case::Pascal], b"FooBar".detect_case().iter().collect::<Vec<_>>());
+	}
+
+	#[test]
+	fn is_case_empty() {
+		assert!(!b"".is_snake());
+		assert!(!b"".is_camel());
+		assert!(!b"__".is_snake());
+		assert!(b"".detect_case().is_empty());
+		assert!(b"--".detect_case().is_empty());
+	}
+
+	#[test]
+	fn words() {
+		fn words(string: &[u8]) -> Vec<(&[u8], Kind)> {
+			string.words(Default::default()).map(|w| (w.text(), w.kind())).collect()
+		}
+
+		assert_eq!(vec![(&b"foo"[..], Kind::Lower), (&b"Bar"[..], Kind::Title)], words(b"fooBar"));
+		assert_eq!(vec![(&b"FOO"[..], Kind::Upper), (&b"BAR"[..], Kind::Upper)], words(b"FOO_BAR"));
+		assert_eq!(vec![(&b"foo"[..], Kind::Lower), (&b"bar"[..], Kind::Lower)], words(b"foo-bar"));
+		assert_eq!(vec![(&b"XML"[..], Kind::Acronym), (&b"Http"[..], Kind::Title), (&b"Request"[..], Kind::Title)], words(b"XMLHttpRequest"));
+		assert_eq!(vec![(&b"utf8"[..], Kind::Lower), (&b"Decoder"[..], Kind::Title)], words(b"utf8Decoder"));
+		assert_eq!(vec![(&b"version"[..], Kind::Lower), (&b"2"[..], Kind::Number)], words(b"version 2"));
+		assert_eq!(vec![("æßð".as_bytes(), Kind::Lower)], words("æßð".as_bytes()));
+		assert_eq!(vec![(&b"foo"[..], Kind::Lower)], words(b"__foo--"));
+		assert!(words(b"_-_").is_empty());
+	}
+
+	#[test]
+	fn words_copy() {
+		let mut words = b"fooBar_baz".words(Default::default());
+		let word      = words.next().unwrap();
+		let copy      = word;
+
+		assert_eq!(word, copy);
+		assert_eq!(words.clone().count(), 2);
+		assert_eq!(words.count(), 2);
+	}
+
+	#[test]
+	fn words_boundary() {
+		fn words<'a>(string: &'a [u8], boundary: Boundary<'a, u8>) -> Vec<&'a [u8]> {
+			string.words(boundary).map(|w| w.text()).collect()
+		}
+
+		assert_eq!(vec![&b"version"[..], b"2", b"Update"], words(b"version2Update", Boundary { digit: true, .. Default::default() }));
+		assert_eq!(vec![&b"v"[..], b"2", b"api"], words(b"v2api", Boundary { digit: true, .. Default::default() }));
+		assert_eq!(vec![&b"fooBar"[..]], words(b"fooBar", Boundary { case: false, .. Default::default() }));
+		assert_eq!(vec![&b"HTTPServer"[..]], words(b"HTTPServer", Boundary { acronym: false, .. Default::default() }));
+		assert_eq!(vec![&b"foo bar"[..], b"baz"], words(b"foo bar_baz", Boundary::from(Separator(&b"_"[..]))));
+		assert_eq!(vec![&b"fooBar"[..], b"baz"], words(b"fooBar.baz", Boundary::only(b".")));
+	}
+
+	#[test]
+	fn words_acronyms() {
+		let boundary = Boundary { acronyms: Acronyms::new(&["HTTP", "HTTPS", "URL"], Initialism::Title), .. Default::default() };
+		let words    = b"HTTPSURLParser".words(boundary).map(|w| (w.text(), w.kind())).collect::<Vec<_>>();
+
+		assert_eq!(vec![(&b"HTTPS"[..], Kind::Acronym), (&b"URL"[..], Kind::Acronym), (&b"Parser"[..], Kind::Title)], words);
+	}
+
+	#[test]
+	fn words_offset() {
+		let offsets = b"foo_barBaz".words(Default::default()).map(|w| w.offset()).collect::<Vec<_>>();
+		assert_eq!(vec![0, 4, 7], offsets);
+	}
 }
 
 #[cfg(test)]
@@ -559,12 +1277,12 @@ mod bench {
 
 	#[bench]
 	fn capitalized_owned(b: &mut Bencher) {
-		b.iter(|| b"aAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA".capitalized(Default::default()));
+		b.iter(|| b"aAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA".capitalized(Default::default(), Default::default()));
 	}
 
 	#[bench]
 	fn capitalized_borrowed(b: &mut Bencher) {
-		b.iter(|| b"Aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".capitalized(Default::default()));
+		b.iter(|| b"Aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".capitalized(Default::default(), Default::default()));
 	}
 
 	#[bench]
@@ -577,6 +1295,16 @@ mod bench {
 		b.iter(|| b"FooBarBaz".header(Default::default()))
 	}
 
+	#[bench]
+	fn title_owned(b: &mut Bencher) {
+		b.iter(|| b"Large red mug".title(Default::default()))
+	}
+
+	#[bench]
+	fn title_borrowed(b: &mut Bencher) {
+		b.iter(|| b"Large Red Mug".title(Default::default()))
+	}
+
 	#[bench]
 	fn header_owned(b: &mut Bencher) {
 		b.iter(|| b"Foo-Bar-baz".header(Default::default()))
@@ -586,4 +1314,9 @@ mod bench {
 	fn header_borrowed(b: &mut Bencher) {
 		b.iter(|| b"Foo-Bar-Baz".header(Default::default()))
 	}
+
+	#[bench]
+	fn words(b: &mut Bencher) {
+		b.iter(|| b"XMLHttpRequest_foo-bar".words(Default::default()).count())
+	}
 }
@@ -0,0 +1,172 @@
+use std::borrow::Cow;
+
+/// Output buffer that keeps borrowing the input as long as what's pushed into
+/// it matches the input, and only allocates once they differ.
+///
+/// When only checking, it never allocates and just remembers if the output
+/// diverged from the input.
+pub struct Buffer<'a, T: ?Sized + ToOwned + 'a> {
+	input:    &'a T,
+	offset:   usize,
+	owned:    Option<T::Owned>,
+	check:    bool,
+	diverged: bool,
+}
+
+impl<'a, T: ?Sized + ToOwned + 'a> Buffer<'a, T> {
+	/// Whether the buffer is only checking and the output already diverged
+	/// from the input, so there's no point in pushing anything else.
+	#[inline(always)]
+	pub fn is_mismatched(&self) -> bool {
+		self.check && self.diverged
+	}
+}
+
+impl<'a> Buffer<'a, str> {
+	#[inline(always)]
+	pub fn new(input: &'a str) -> Self {
+		Buffer { input, offset: 0, owned: None, check: false, diverged: false }
+	}
+
+	/// Creates a buffer that only checks if the output matches the input.
+	#[inline(always)]
+	pub fn check(input: &'a str) -> Self {
+		Buffer { input, offset: 0, owned: None, check: true, diverged: false }
+	}
+
+	/// Whether the whole output matched the whole input.
+	#[inline(always)]
+	pub fn matches(&self) -> bool {
+		!self.diverged && self.offset == self.input.len()
+	}
+
+	#[inline(always)]
+	pub fn push(&mut self, ch: char) {
+		if let Some(ref mut owned) = self.owned {
+			owned.push(ch);
+		}
+		else {
+			self.push_str(ch.encode_utf8(&mut [0; 4]));
+		}
+	}
+
+	#[inline(always)]
+	pub fn push_str(&mut self, string: &str) {
+		if let Some(ref mut owned) = self.owned {
+			owned.push_str(string);
+			return;
+		}
+
+		if self.diverged {
+			return;
+		}
+
+		if self.input[self.offset ..].starts_with(string) {
+			self.offset += string.len();
+		}
+		else if self.check {
+			self.diverged = true;
+		}
+		else {
+			self.diverged = true;
+
+			let mut owned = String::with_capacity(self.input.len());
+			owned.push_str(&self.input[.. self.offset]);
+			owned.push_str(string);
+
+			self.owned = Some(owned);
+		}
+	}
+
+	#[inline(always)]
+	pub fn extend<I: IntoIterator<Item = char>>(&mut self, chars: I) {
+		for ch in chars {
+			self.push(ch);
+		}
+	}
+
+	/// Returns the output, borrowing the input if nothing diverged from it.
+	#[inline(always)]
+	pub fn finish(self) -> Cow<'a, str> {
+		match self.owned {
+			Some(owned) => Cow::Owned(owned),
+			None        => Cow::Borrowed(&self.input[.. self.offset]),
+		}
+	}
+}
+
+impl<'a> Buffer<'a, [u8]> {
+	#[inline(always)]
+	pub fn new(input: &'a [u8]) -> Self {
+		Buffer { input, offset: 0, owned: None, check: false, diverged: false }
+	}
+
+	/// Creates a buffer that only checks if the output matches the input.
+	#[inline(always)]
+	pub fn check(input: &'a [u8]) -> Self {
+		Buffer { input, offset: 0, owned: None, check: true, diverged: false }
+	}
+
+	/// Whether the whole output matched the whole input.
+	#[inline(always)]
+	pub fn matches(&self) -> bool {
+		!self.diverged && self.offset == self.input.len()
+	}
+
+	#[inline(always)]
+	pub fn push(&mut self, ch: u8) {
+		if let Some(ref mut owned) = self.owned {
+			owned.push(ch);
+		}
+		else if !self.diverged && self.input.get(self.offset) == Some(&ch) {
+			self.offset += 1;
+		}
+		else {
+			self.push_slice(&[ch]);
+		}
+	}
+
+	#[inline(always)]
+	pub fn push_slice(&mut self, slice: &[u8]) {
+		if let Some(ref mut owned) = self.owned {
+			owned.extend_from_slice(slice);
+			return;
+		}
+
+		if self.diverged {
+			return;
+		}
+
+		if self.input[self.offset ..].starts_with(slice) {
+			self.offset += slice.len();
+		}
+		else if self.check {
+			self.diverged = true;
+		}
+		else {
+			self.diverged = true;
+
+			let mut owned = Vec::with_capacity(self.input.len());
+			owned.extend_from_slice(&self.input[.. self.offset]);
+			owned.extend_from_slice(slice);
+
+			self.owned = Some(owned);
+		}
+	}
+
+	#[inline(always)]
+	pub fn extend<I: IntoIterator<Item = u8>>(&mut self, bytes: I) {
+		for ch in bytes {
+			self.push(ch);
+		}
+	}
+
+	/// Returns the output, borrowing the input if nothing diverged from it.
+	#[inline(always)]
+	pub fn finish(self) -> Cow<'a, [u8]> {
+		match self.owned {
+			Some(owned) => Cow::Owned(owned),
+			None        => Cow::Borrowed(&self.input[.. self.offset]),
+		}
+	}
+}
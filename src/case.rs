@@ -0,0 +1,252 @@
+use std::fmt;
+use std::error::Error;
+use std::str::FromStr;
+use std::iter::FromIterator;
+use {Casing, Boundary};
+use words::Pattern;
+
+/// A naming convention.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash)]
+pub enum Case {
+	/// `snake_case`
+	Snake,
+
+	/// `kebab-case`
+	Kebab,
+
+	/// `SCREAMING_SNAKE_CASE`
+	ScreamingSnake,
+
+	/// `camelCase`
+	Camel,
+
+	/// `PascalCase`
+	Pascal,
+
+	/// `Train-Case`
+	Train,
+
+	/// `Header-Case`, like `Train-Case` but upper case characters after the
+	/// first of each word are not lower cased.
+	Header,
+
+	/// `Title Case`
+	Title,
+
+	/// `Sentence case`
+	Sentence,
+
+	/// `flatcase`
+	Flat,
+
+	/// `UPPERFLATCASE`
+	UpperFlat,
+
+	/// `dot.case`
+	Dot,
+
+	/// `path/case`
+	Path,
+
+	/// `COBOL-CASE`
+	Cobol,
+
+	/// `Ada_Case`
+	Ada,
+}
+
+impl Case {
+	/// All the cases.
+	pub const ALL: &'static [Case] = &[
+		Case::Snake, Case::Kebab, Case::ScreamingSnake, Case::Camel, Case::Pascal,
+		Case::Train, Case::Header, Case::Title, Case::Sentence, Case::Flat,
+		Case::UpperFlat, Case::Dot, Case::Path, Case::Cobol, Case::Ada,
+	];
+
+	/// How the first and the rest of the words are cased, and what separates
+	/// them.
+	#[inline(always)]
+	pub(crate) fn patterns(self) -> (Pattern, Pattern, Option<u8>) {
+		match self {
+			Case::Snake          => (Pattern::Lower, Pattern::Lower, Some(b'_')),
+			Case::Kebab          => (Pattern::Lower, Pattern::Lower, Some(b'-')),
+			Case::ScreamingSnake => (Pattern::Upper, Pattern::Upper, Some(b'_')),
+			Case::Camel          => (Pattern::Lower, Pattern::Title, None),
+			Case::Pascal         => (Pattern::Title, Pattern::Title, None),
+			Case::Train          => (Pattern::Title, Pattern::Title, Some(b'-')),
+			Case::Header         => (Pattern::Capital, Pattern::Capital, Some(b'-')),
+			Case::Title          => (Pattern::Title, Pattern::Title, Some(b' ')),
+			Case::Sentence       => (Pattern::Title, Pattern::Lower, Some(b' ')),
+			Case::Flat           => (Pattern::Lower, Pattern::Lower, None),
+			Case::UpperFlat      => (Pattern::Upper, Pattern::Upper, None),
+			Case::Dot            => (Pattern::Lower, Pattern::Lower, Some(b'.')),
+			Case::Path           => (Pattern::Lower, Pattern::Lower, Some(b'/')),
+			Case::Cobol          => (Pattern::Upper, Pattern::Upper, Some(b'-')),
+			Case::Ada            => (Pattern::Title, Pattern::Title, Some(b'_')),
+		}
+	}
+}
+
+impl fmt::Display for Case {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(match *self {
+			Case::Snake          => "snake_case",
+			Case::Kebab          => "kebab-case",
+			Case::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+			Case::Camel          => "camelCase",
+			Case::Pascal         => "PascalCase",
+			Case::Train          => "Train-Case",
+			Case::Header         => "Header-Case",
+			Case::Title          => "Title Case",
+			Case::Sentence       => "Sentence case",
+			Case::Flat           => "flatcase",
+			Case::UpperFlat      => "UPPERFLATCASE",
+			Case::Dot            => "dot.case",
+			Case::Path           => "path/case",
+			Case::Cobol          => "COBOL-CASE",
+			Case::Ada            => "Ada_Case",
+		})
+	}
+}
+
+/// A set of `Case`s.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default, Hash)]
+pub struct Cases(u32);
+
+impl Cases {
+	/// Creates an empty set.
+	pub fn new() -> Self {
+		Cases(0)
+	}
+
+	/// Adds a case to the set.
+	pub fn insert(&mut self, case: Case) {
+		self.0 |= 1 << case as u32;
+	}
+
+	/// Checks if the case is in the set.
+	pub fn contains(&self, case: Case) -> bool {
+		self.0 & (1 << case as u32) != 0
+	}
+
+	/// Checks if the set is empty.
+	pub fn is_empty(&self) -> bool {
+		self.0 == 0
+	}
+
+	/// The number of cases in the set.
+	pub fn len(&self) -> usize {
+		self.0.count_ones() as usize
+	}
+
+	/// Iterates over the cases in the set.
+	pub fn iter(&self) -> impl Iterator<Item = Case> {
+		let this = *self;
+		Case::ALL.iter().cloned().filter(move |&case| this.contains(case))
+	}
+}
+
+impl FromIterator<Case> for Cases {
+	fn from_iter<I: IntoIterator<Item = Case>>(iter: I) -> Self {
+		let mut cases = Cases::new();
+
+		for case in iter {
+			cases.insert(case);
+		}
+
+		cases
+	}
+}
+
+/// Error returned when parsing an unknown `Case` name.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct ParseCaseError(());
+
+impl fmt::Display for ParseCaseError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("unknown case")
+	}
+}
+
+impl Error for ParseCaseError { }
+
+/// Parses the name of a case in any style, `snake_case`, `SnakeCase`,
+/// `snake-case` and `snake` are all the same; some common aliases are accepted
+/// as well.
+impl FromStr for Case {
+	type Err = ParseCaseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut name = String::with_capacity(s.len());
+
+		for word in s.words(Boundary { acronym: false, .. Default::default() }) {
+			name.push_str(&word.to_ascii_lowercase());
+		}
+
+		if name.len() > 4 && name.ends_with("case") {
+			let length = name.len() - 4;
+			name.truncate(length);
+		}
+
+		Ok(match &*name {
+			"snake" | "lowersnake"                           => Case::Snake,
+			"kebab" | "dash" | "dashed" | "lisp" | "spinal" => Case::Kebab,
+			"screamingsnake" | "uppersnake" | "constant"     => Case::ScreamingSnake,
+			"camel" | "lowercamel" | "dromedary"             => Case::Camel,
+			"pascal" | "uppercamel"                          => Case::Pascal,
+			"train"                                          => Case::Train,
+			"header" | "http"                                => Case::Header,
+			"title"                                          => Case::Title,
+			"sentence"                                       => Case::Sentence,
+			"flat" | "lowerflat"                             => Case::Flat,
+			"upperflat" | "screamingflat"                    => Case::UpperFlat,
+			"dot" | "dotted"                                 => Case::Dot,
+			"path" | "slash"                                 => Case::Path,
+			"cobol" | "screamingkebab" | "upperkebab"        => Case::Cobol,
+			"ada" | "pascalsnake" | "titlesnake"             => Case::Ada,
+			_                                                => return Err(ParseCaseError(())),
+		})
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use {Case, Cases};
+
+	#[test]
+	fn display() {
+		assert_eq!("snake_case", Case::Snake.to_string());
+		assert_eq!("kebab-case", Case::Kebab.to_string());
+		assert_eq!("SCREAMING_SNAKE_CASE", Case::ScreamingSnake.to_string());
+	}
+
+	#[test]
+	fn from_str() {
+		for &case in Case::ALL {
+			assert_eq!(Ok(case), case.to_string().parse());
+		}
+
+		assert_eq!(Ok(Case::Snake), "snake".parse());
+		assert_eq!(Ok(Case::Snake), "SnakeCase".parse());
+		assert_eq!(Ok(Case::Kebab), "kebab_case".parse());
+		assert_eq!(Ok(Case::Pascal), "UpperCamelCase".parse());
+		assert_eq!(Ok(Case::ScreamingSnake), "CONSTANT_CASE".parse());
+		assert_eq!(Ok(Case::UpperFlat), "upperflatcase".parse());
+		assert_eq!(Ok(Case::Cobol), "SCREAMING-KEBAB-CASE".parse());
+		assert_eq!(Ok(Case::Ada), "Pascal_Snake_Case".parse());
+
+		assert!("case".parse::<Case>().is_err());
+		assert!("wobbly".parse::<Case>().is_err());
+	}
+
+	#[test]
+	fn cases() {
+		let cases = vec![Case::Snake, Case::Flat].into_iter().collect::<Cases>();
+
+		assert_eq!(2, cases.len());
+		assert!(cases.contains(Case::Snake));
+		assert!(!cases.contains(Case::Kebab));
+		assert_eq!(vec![Case::Snake, Case::Flat], cases.iter().collect::<Vec<_>>());
+		assert!(Cases::new().is_empty());
+	}
+}
@@ -0,0 +1,43 @@
+use std::cmp::Ordering;
+use tables::{COMBINING, MARKS};
+
+/// The canonical combining class of the character, zero for anything that
+/// isn't a combining mark.
+#[inline(always)]
+pub fn class(ch: char) -> u8 {
+	match COMBINING.binary_search_by(|&(start, end, _)| range(ch, start, end)) {
+		Ok(index) => COMBINING[index].2,
+		Err(..)   => 0,
+	}
+}
+
+/// Whether the character is a combining mark, which belongs to the character
+/// preceding it, `é` as `e` followed by U+0301.
+#[inline(always)]
+pub fn is_mark(ch: char) -> bool {
+	!ch.is_ascii() && MARKS.binary_search_by(|&(start, end)| range(ch, start, end)).is_ok()
+}
+
+/// The length of the combining marks the text starts with, which belong to
+/// whatever character precedes the text.
+#[inline(always)]
+pub fn marks(text: &str) -> usize {
+	text.char_indices()
+		.find(|&(_, ch)| class(ch) == 0)
+		.map_or(text.len(), |(i, _)| i)
+}
+
+/// Compares the character with an inclusive range, for binary searches over
+/// tables of ranges.
+#[inline(always)]
+pub fn range(ch: char, start: char, end: char) -> Ordering {
+	if ch < start {
+		Ordering::Greater
+	}
+	else if ch > end {
+		Ordering::Less
+	}
+	else {
+		Ordering::Equal
+	}
+}
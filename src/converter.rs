@@ -0,0 +1,195 @@
+use {Case, Locale, Boundary, Symbols, Acronyms, Initialism};
+use words::Pattern;
+
+/// How the characters after the first one are cased in words that start
+/// with an upper case character.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Body {
+	/// Leave them as they are, `MIME-Type`.
+	Keep,
+
+	/// Turn them to lower case, `Mime-Type`.
+	Lower,
+}
+
+/// A reusable conversion to a `Case`, holding all the options so they don't
+/// have to be passed around on every call.
+///
+/// The builder methods are `const` so a `Converter` can live in a `static`
+/// and be shared between threads.
+///
+/// ```
+/// use casing::{Converter, Case};
+///
+/// static SNAKE: Converter<char> = Converter::new(Case::Snake).digit(true);
+///
+/// assert_eq!("version_2_update", SNAKE.convert("version2Update"));
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Converter<'a, T: Copy + Eq + 'a> {
+	pub(crate) case:      Case,
+	pub(crate) locale:    Locale,
+	pub(crate) boundary:  Boundary<'a, T>,
+	pub(crate) separator: Option<T>,
+	pub(crate) body:      Option<Body>,
+}
+
+impl<T: Copy + Eq> Converter<'static, T> {
+	/// Creates a converter to the given case with the default options.
+	pub const fn new(case: Case) -> Self {
+		Converter {
+			case,
+			locale:    Locale::None,
+			boundary:  Boundary {
+				separators: &[],
+				symbols:    Symbols::Separate,
+				case:       true,
+				acronym:    true,
+				digit:      false,
+				acronyms:   Acronyms::new(&[], Initialism::Title),
+			},
+			separator: None,
+			body:      None,
+		}
+	}
+}
+
+impl<'a, T: Copy + Eq + 'a> Converter<'a, T> {
+	/// Sets the locale.
+	pub const fn locale(mut self, locale: Locale) -> Self {
+		self.locale = locale;
+		self
+	}
+
+	/// Sets the characters separating words in the input, instead of any
+	/// character that is neither a letter nor a digit.
+	///
+	/// Any other symbol is then attached to its word, like with a `Separator`,
+	/// so this resets `symbols` to `Symbols::Attach`; call `symbols` after it
+	/// to pick something else.
+	pub const fn separators<'b>(self, separators: &'b [T]) -> Converter<'b, T> where 'a: 'b {
+		Converter {
+			boundary: Boundary {
+				separators,
+				symbols:  Symbols::Attach,
+				case:     self.boundary.case,
+				acronym:  self.boundary.acronym,
+				digit:    self.boundary.digit,
+				acronyms: self.boundary.acronyms,
+			},
+
+			case:      self.case,
+			locale:    self.locale,
+			separator: self.separator,
+			body:      self.body,
+		}
+	}
+
+	/// Sets all the rules splitting the input into words at once.
+	pub const fn boundary<'b>(self, boundary: Boundary<'b, T>) -> Converter<'b, T> where 'a: 'b {
+		Converter {
+			boundary,
+
+			case:      self.case,
+			locale:    self.locale,
+			separator: self.separator,
+			body:      self.body,
+		}
+	}
+
+	/// Sets the separator between words in the output, replacing the one of
+	/// the case.
+	pub const fn separator(mut self, separator: T) -> Self {
+		self.separator = Some(separator);
+		self
+	}
+
+	/// Sets the known acronyms.
+	pub const fn acronyms<'b>(self, acronyms: Acronyms<'b>) -> Converter<'b, T> where 'a: 'b {
+		Converter {
+			boundary: Boundary {
+				acronyms,
+				separators: self.boundary.separators,
+				symbols:    self.boundary.symbols,
+				case:       self.boundary.case,
+				acronym:    self.boundary.acronym,
+				digit:      self.boundary.digit,
+			},
+
+			case:      self.case,
+			locale:    self.locale,
+			separator: self.separator,
+			body:      self.body,
+		}
+	}
+
+	/// Sets what happens to characters that are neither letters nor digits.
+	pub const fn symbols(mut self, symbols: Symbols) -> Self {
+		self.boundary.symbols = symbols;
+		self
+	}
+
+	/// Sets whether letters and digits are split into different words,
+	/// `version_2_update` instead of `version2_update`.
+	pub const fn digit(mut self, value: bool) -> Self {
+		self.boundary.digit = value;
+		self
+	}
+
+	/// Sets how the rest of capitalized words are cased, replacing the
+	/// behavior of the case.
+	pub const fn body(mut self, body: Body) -> Self {
+		self.body = Some(body);
+		self
+	}
+
+	/// The patterns for the first and rest of the words, with the separator.
+	#[inline(always)]
+	pub(crate) fn patterns(&self) -> (Pattern, Pattern, Option<T>) where T: From<u8> {
+		let (first, rest, separator) = self.case.patterns();
+
+		(self.pattern(first), self.pattern(rest), self.separator.or(separator.map(T::from)))
+	}
+
+	#[inline(always)]
+	fn pattern(&self, pattern: Pattern) -> Pattern {
+		match (pattern, self.body) {
+			(Pattern::Title, Some(Body::Keep))    => Pattern::Capital,
+			(Pattern::Capital, Some(Body::Lower)) => Pattern::Title,
+			(pattern, _)                          => pattern,
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use {Converter, Case, Body, Symbols, Acronyms, Initialism};
+
+	static SNAKE: Converter<char> = Converter::new(Case::Snake).digit(true);
+
+	fn shared<T: Send + Sync>(_value: &T) { }
+
+	#[test]
+	fn send_sync() {
+		shared(&SNAKE);
+		shared(&Converter::<u8>::new(Case::Pascal));
+	}
+
+	#[test]
+	fn convert() {
+		let go = Converter::<char>::new(Case::Pascal)
+			.acronyms(Acronyms::new(Acronyms::COMMON, Initialism::Upper));
+
+		assert_eq!("version_2_update", SNAKE.convert("version2Update"));
+		assert_eq!("ServeHTTP", go.convert("serve_http"));
+		assert_eq!("foo.bar", Converter::new(Case::Snake).separator('.').convert("fooBar"));
+		assert_eq!("foo_bar-baz", Converter::new(Case::Snake).separators(&['.']).convert("foo.bar-baz"));
+		assert_eq!("MIME-Type", Converter::<char>::new(Case::Train).body(Body::Keep).convert("MIME_type"));
+		assert_eq!("Mime-Type", Converter::<char>::new(Case::Header).body(Body::Lower).convert("MIME_type"));
+		assert_eq!("total_price_usd", Converter::<char>::new(Case::Snake).symbols(Symbols::Drop).convert("Total Price (USD)"));
+		assert_eq!("foo_bar-baz", Converter::new(Case::Snake).symbols(Symbols::Drop).separators(&['.']).convert("foo.bar-baz"));
+		assert_eq!("foo_barbaz", Converter::new(Case::Snake).separators(&['.']).symbols(Symbols::Drop).convert("foo.bar-baz"));
+
+		assert_eq!(&b"foo_bar"[..], &*Converter::<u8>::new(Case::Snake).convert(b"FooBar"));
+	}
+}
@@ -0,0 +1,141 @@
+use std::ops::Range;
+use std::str::{Chars, Utf8Chunks};
+use Locale;
+use tables::{SIMPLE_FOLDING, FULL_FOLDING, TURKIC_FOLDING};
+
+/// The Turkic case folding of the character, if it differs from the default
+/// one, `I` to `ı`.
+#[inline(always)]
+fn turkic(ch: char, locale: Locale) -> Option<char> {
+	if !locale.is_turkic() {
+		return None;
+	}
+
+	TURKIC_FOLDING.binary_search_by_key(&ch, |&(ch, _)| ch).ok().map(|index| TURKIC_FOLDING[index].1)
+}
+
+/// The simple case folding of the character, always a single character.
+#[inline(always)]
+pub fn simple(ch: char, locale: Locale) -> char {
+	if let Some(folded) = turkic(ch, locale) {
+		return folded;
+	}
+
+	if ch.is_ascii() {
+		return ch.to_ascii_lowercase();
+	}
+
+	match SIMPLE_FOLDING.binary_search_by_key(&ch, |&(ch, _)| ch) {
+		Ok(index) => SIMPLE_FOLDING[index].1,
+		Err(..)   => ch,
+	}
+}
+
+/// The full case folding of the character, if it expands to more than one
+/// character, `ß` to `ss`.
+#[inline(always)]
+pub fn full(ch: char, locale: Locale) -> Option<&'static str> {
+	if turkic(ch, locale).is_some() {
+		return None;
+	}
+
+	FULL_FOLDING.binary_search_by_key(&ch, |&(ch, _)| ch).ok().map(|index| FULL_FOLDING[index].1)
+}
+
+/// Iterator over the full case folding of a string, folding each character
+/// lazily.
+pub struct Folded<'a> {
+	chars:   Chars<'a>,
+	locale:  Locale,
+	pending: Chars<'static>,
+}
+
+impl<'a> Folded<'a> {
+	#[inline(always)]
+	pub fn new(text: &'a str, locale: Locale) -> Self {
+		Folded {
+			chars:   text.chars(),
+			locale,
+			pending: "".chars(),
+		}
+	}
+}
+
+impl<'a> Iterator for Folded<'a> {
+	type Item = char;
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<char> {
+		if let Some(ch) = self.pending.next() {
+			return Some(ch);
+		}
+
+		let ch = self.chars.next()?;
+
+		match full(ch, self.locale) {
+			Some(folded) => {
+				self.pending = folded.chars();
+				self.pending.next()
+			}
+
+			None =>
+				Some(simple(ch, self.locale))
+		}
+	}
+}
+
+/// Iterator over the full case folding of bytes, folding the valid UTF-8 in
+/// them and keeping any invalid byte as it is.
+pub struct FoldedBytes<'a> {
+	chunks:  Utf8Chunks<'a>,
+	locale:  Locale,
+	folded:  Folded<'a>,
+	invalid: &'a [u8],
+	buffer:  [u8; 4],
+	pending: Range<usize>,
+}
+
+impl<'a> FoldedBytes<'a> {
+	#[inline(always)]
+	pub fn new(bytes: &'a [u8], locale: Locale) -> Self {
+		FoldedBytes {
+			chunks:  bytes.utf8_chunks(),
+			locale,
+			folded:  Folded::new("", locale),
+			invalid: &[],
+			buffer:  [0; 4],
+			pending: 0 .. 0,
+		}
+	}
+}
+
+impl<'a> Iterator for FoldedBytes<'a> {
+	type Item = u8;
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<u8> {
+		loop {
+			if let Some(index) = self.pending.next() {
+				return Some(self.buffer[index]);
+			}
+
+			if let Some(ch) = self.folded.next() {
+				if ch.is_ascii() {
+					return Some(ch as u8);
+				}
+
+				self.pending = 0 .. ch.encode_utf8(&mut self.buffer).len();
+				continue;
+			}
+
+			if let Some((&byte, rest)) = self.invalid.split_first() {
+				self.invalid = rest;
+				return Some(byte);
+			}
+
+			let chunk = self.chunks.next()?;
+			self.folded  = Folded::new(chunk.valid(), self.locale);
+			self.invalid = chunk.invalid();
+		}
+	}
+}
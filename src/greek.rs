@@ -0,0 +1,180 @@
+use buffer::Buffer;
+use tables::GREEK;
+
+// Flags for the diacritics on a Greek letter, must match `scripts/tables.py`.
+const VOWEL:         u8 = 1 << 0;
+const ACCENT:        u8 = 1 << 1;
+const DIALYTIKA:     u8 = 1 << 2;
+const YPOGEGRAMMENI: u8 = 1 << 3;
+
+/// Upper cases the text following the Greek rules: accents and breathings are
+/// dropped, the dialytika is kept, and added where dropping an accent would
+/// otherwise turn two vowels into a diphthong, `Μάιος` to `ΜΑΪΟΣ`.
+pub fn upper(result: &mut Buffer<str>, text: &str) {
+	let mut chars = text.char_indices().peekable();
+
+	// Whether the previous character was a cased letter.
+	let mut after_cased = false;
+
+	// Whether the previous character was a vowel with an accent and no dialytika.
+	let mut after_accent = false;
+
+	while let Some((_, ch)) = chars.next() {
+		let (upper, mut flags) = match letter(ch) {
+			Some(letter) =>
+				letter,
+
+			None => {
+				result.extend(ch.to_uppercase());
+				after_cased  = is_cased(ch);
+				after_accent = false;
+
+				continue;
+			}
+		};
+
+		if flags & VOWEL != 0 && after_accent && (upper == 'Ι' || upper == 'Υ') {
+			flags |= DIALYTIKA;
+		}
+
+		let mut ypogegrammeni = if flags & YPOGEGRAMMENI != 0 { 1 } else { 0 };
+
+		// Skip the combining diacritics following the letter.
+		while let Some(&(_, next)) = chars.peek() {
+			let diacritic = match diacritic(next) {
+				Some(diacritic) => diacritic,
+				None            => break,
+			};
+
+			if diacritic & YPOGEGRAMMENI != 0 {
+				ypogegrammeni += 1;
+			}
+
+			flags |= diacritic;
+			chars.next();
+		}
+
+		let rest = chars.peek().map(|&(i, _)| &text[i ..]).unwrap_or("");
+
+		// The disjunctive `ή` keeps its accent, or it would read as an article.
+		if upper == 'Η' && flags & ACCENT != 0 && ypogegrammeni == 0 && !after_cased && !is_followed_by_cased(rest) {
+			result.push('Ή');
+		}
+		else if flags & DIALYTIKA != 0 {
+			match upper {
+				'Ι' => result.push('Ϊ'),
+				'Υ' => result.push('Ϋ'),
+
+				upper => {
+					result.push(upper);
+					result.push('\u{308}');
+				}
+			}
+		}
+		else {
+			result.push(upper);
+		}
+
+		for _ in 0 .. ypogegrammeni {
+			result.push('Ι');
+		}
+
+		after_cased  = true;
+		after_accent = flags & (VOWEL | ACCENT | DIALYTIKA) == VOWEL | ACCENT;
+	}
+}
+
+/// Lower cases a capital sigma at the given offset, to `ς` when it ends a
+/// word and to `σ` otherwise, following the Final_Sigma context.
+#[inline(always)]
+pub fn sigma(text: &str, offset: usize) -> char {
+	let before = text[.. offset].chars().rev()
+		.find(|&ch| !is_case_ignorable(ch))
+		.is_some_and(is_cased);
+
+	if before && !is_followed_by_cased(&text[offset + 'Σ'.len_utf8() ..]) {
+		'ς'
+	}
+	else {
+		'σ'
+	}
+}
+
+/// The upper case base letter and the diacritics of a Greek letter.
+#[inline(always)]
+fn letter(ch: char) -> Option<(char, u8)> {
+	if let Ok(index) = GREEK.binary_search_by_key(&ch, |&(ch, _, _)| ch) {
+		let (_, upper, flags) = GREEK[index];
+		return Some((upper, flags));
+	}
+
+	if !is_greek(ch) || !ch.is_alphabetic() {
+		return None;
+	}
+
+	let mut upper = ch.to_uppercase();
+
+	match (upper.next(), upper.next()) {
+		(Some(upper), None) =>
+			Some((upper, if is_vowel(upper) { VOWEL } else { 0 })),
+
+		_ =>
+			None
+	}
+}
+
+/// The flags for a combining diacritic used in Greek.
+#[inline(always)]
+fn diacritic(ch: char) -> Option<u8> {
+	match ch {
+		'\u{300}' | '\u{301}' | '\u{302}' | '\u{303}' | '\u{311}' | '\u{342}' =>
+			Some(ACCENT),
+
+		'\u{308}' =>
+			Some(DIALYTIKA),
+
+		'\u{344}' =>
+			Some(DIALYTIKA | ACCENT),
+
+		'\u{345}' =>
+			Some(YPOGEGRAMMENI),
+
+		'\u{304}' | '\u{306}' | '\u{313}' | '\u{314}' | '\u{343}' =>
+			Some(0),
+
+		_ =>
+			None
+	}
+}
+
+#[inline(always)]
+fn is_greek(ch: char) -> bool {
+	('\u{370}' ..= '\u{3ff}').contains(&ch) || ('\u{1f00}' ..= '\u{1fff}').contains(&ch)
+}
+
+#[inline(always)]
+fn is_vowel(ch: char) -> bool {
+	matches!(ch, 'Α' | 'Ε' | 'Η' | 'Ι' | 'Ο' | 'Υ' | 'Ω')
+}
+
+/// Whether the text starts with a cased letter, ignoring any case ignorable
+/// characters before it.
+#[inline(always)]
+fn is_followed_by_cased(text: &str) -> bool {
+	text.chars().find(|&ch| !is_case_ignorable(ch)).is_some_and(is_cased)
+}
+
+#[inline(always)]
+fn is_cased(ch: char) -> bool {
+	ch.is_lowercase() || ch.is_uppercase() || ch.to_lowercase().next() != Some(ch)
+}
+
+/// Approximation of the Case_Ignorable property, covering the punctuation
+/// within words, modifier letters and combining marks.
+#[inline(always)]
+fn is_case_ignorable(ch: char) -> bool {
+	matches!(ch,
+		'\'' | '.' | ':' | '^' | '`' | '\u{ad}' | '\u{b7}' | '\u{2018}' | '\u{2019}' | '\u{2024}' | '\u{2027}' |
+		'\u{2b0}' ..= '\u{36f}' | '\u{483}' ..= '\u{489}' | '\u{1ab0}' ..= '\u{1aff}' |
+		'\u{1dc0}' ..= '\u{1dff}' | '\u{20d0}' ..= '\u{20ff}' | '\u{fe20}' ..= '\u{fe2f}')
+}
@@ -0,0 +1,293 @@
+use std::str;
+use Locale;
+use words::Pattern;
+
+/// A style guide for title casing English headlines, see `Casing::headline`.
+///
+/// All of them capitalize the first and last word, the first word after a
+/// colon, and each part of hyphenated compounds; words with intentional mixed
+/// case, `iPhone`, and words in upper case, `NASA`, are kept as they are.
+/// They differ on which minor words stay in lower case.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub enum Style {
+	/// The Chicago Manual of Style, articles, prepositions of any length and
+	/// the coordinating conjunctions `and`, `but`, `for`, `or` and `nor`.
+	#[default]
+	Chicago,
+
+	/// The APA style, articles, conjunctions and prepositions of three letters
+	/// or fewer.
+	Apa,
+
+	/// The AP style, like APA.
+	Ap,
+
+	/// The MLA style, articles, prepositions of any length and all coordinating
+	/// conjunctions.
+	Mla,
+}
+
+const PREPOSITIONS: &[&str] = &[
+	"about", "above", "across", "after", "against", "along", "amid", "among",
+	"around", "as", "at", "atop", "before", "behind", "below", "beneath",
+	"beside", "besides", "between", "beyond", "by", "despite", "down", "during",
+	"except", "for", "from", "in", "inside", "into", "like", "near", "of", "off",
+	"on", "onto", "opposite", "out", "outside", "over", "past", "per", "since",
+	"than", "through", "throughout", "till", "to", "toward", "towards", "under",
+	"underneath", "unlike", "until", "up", "upon", "versus", "via", "vs", "with",
+	"within", "without",
+];
+
+const CHICAGO: &[&str] = &[
+	"a", "an", "the", "and", "but", "for", "nor", "or",
+];
+
+const APA: &[&str] = &[
+	"a", "an", "the", "and", "as", "but", "for", "if", "nor", "or", "so", "yet",
+	"at", "by", "in", "of", "off", "on", "per", "to", "up", "via",
+];
+
+const AP: &[&str] = &[
+	"a", "an", "the", "and", "but", "for", "nor", "or", "so", "yet", "at", "by",
+	"in", "of", "off", "on", "out", "per", "to", "up", "via",
+];
+
+const MLA: &[&str] = &[
+	"a", "an", "the", "and", "but", "for", "nor", "or", "so", "yet",
+];
+
+const ITALIAN: &[&str] = &[
+	"il", "lo", "la", "i", "gli", "le", "un", "uno", "una", "di", "a", "da",
+	"in", "con", "su", "per", "tra", "fra", "del", "dello", "della", "dei",
+	"degli", "delle", "al", "allo", "alla", "ai", "agli", "alle", "dal", "dallo",
+	"dalla", "dai", "dagli", "dalle", "nel", "nello", "nella", "nei", "negli",
+	"nelle", "col", "coi", "sul", "sullo", "sulla", "sui", "sugli", "sulle", "e",
+	"ed", "o", "od", "ma",
+];
+
+const PORTUGUESE: &[&str] = &[
+	"o", "a", "os", "as", "um", "uma", "uns", "umas", "de", "do", "da", "dos",
+	"das", "em", "no", "na", "nos", "nas", "por", "pelo", "pela", "pelos",
+	"pelas", "ao", "aos", "à", "às", "com", "para", "sem", "sob", "e", "ou",
+	"mas", "nem",
+];
+
+/// How a language capitalizes headlines.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub(crate) enum Rules {
+	/// Every word is capitalized except for the minor words in the lists,
+	/// like in English.
+	Title(&'static [&'static str], &'static [&'static str]),
+
+	/// Only the first word is capitalized, like in French and Spanish; other
+	/// capitalized words are kept as proper nouns, unless every word is
+	/// capitalized anyway.
+	Sentence { proper: bool },
+
+	/// Only the first word is capitalized and the rest is kept as it is, like
+	/// in German where nouns are always capitalized.
+	Keep,
+}
+
+impl Rules {
+	/// The rules for the language of the locale, using the style only for
+	/// English, `Locale::None`.
+	pub fn new(style: Style, locale: Locale) -> Self {
+		match locale {
+			Locale::French | Locale::Spanish | Locale::Dutch | Locale::Greek |
+			Locale::Turkish | Locale::Azerbaijani | Locale::Lithuanian | Locale::Irish =>
+				Rules::Sentence { proper: true },
+
+			Locale::German =>
+				Rules::Keep,
+
+			Locale::Italian =>
+				Rules::Title(ITALIAN, &[]),
+
+			Locale::Portuguese =>
+				Rules::Title(PORTUGUESE, &[]),
+
+			_ => match style {
+				Style::Chicago => Rules::Title(CHICAGO, PREPOSITIONS),
+				Style::Apa     => Rules::Title(APA, &[]),
+				Style::Ap      => Rules::Title(AP, &[]),
+				Style::Mla     => Rules::Title(MLA, PREPOSITIONS),
+			}
+		}
+	}
+
+	/// Whether the word stays in lower case in the middle of a headline,
+	/// compared ignoring case.
+	#[inline(always)]
+	pub fn is_minor(&self, word: &[u8]) -> bool {
+		let found = |list: &[&str]| find(list, word).is_some();
+
+		match *self {
+			Rules::Title(list, extra) => found(list) || found(extra),
+			_                         => false,
+		}
+	}
+
+	/// How a word of a headline is cased.
+	///
+	/// Whether the word is first or last in the title, whether its casing
+	/// looks intentional, `iPhone`, and whether it starts in upper case.
+	#[inline(always)]
+	pub fn pattern(&self, word: &[u8], first: bool, last: bool, intentional: bool, capitalized: bool) -> Pattern {
+		if word.is_empty() {
+			return Pattern::Keep;
+		}
+
+		match *self {
+			Rules::Title(..) if intentional =>
+				Pattern::Keep,
+
+			Rules::Title(..) if !first && !last && self.is_minor(word) =>
+				Pattern::Lower,
+
+			Rules::Title(..) =>
+				Pattern::Title,
+
+			Rules::Sentence { proper } if intentional || (!first && proper && capitalized) =>
+				Pattern::Keep,
+
+			Rules::Sentence { .. } if first =>
+				Pattern::Title,
+
+			Rules::Sentence { .. } =>
+				Pattern::Lower,
+
+			Rules::Keep if first =>
+				Pattern::Capital,
+
+			Rules::Keep =>
+				Pattern::Keep,
+		}
+	}
+}
+
+/// Common abbreviations ending with a period that don't end a sentence, words
+/// with periods inside, `e.g.`, and single letters, `J.`, are abbreviations
+/// as well.
+///
+/// Abbreviations that are also common words, like `no` and `st`, are left out
+/// since they end sentences just as often.
+const ABBREVIATIONS: &[&str] = &[
+	"dr", "mr", "mrs", "ms", "prof", "sr", "jr", "mt", "vs", "cf", "vol", "fig",
+	"approx", "dept", "inc", "ltd", "corp", "gov", "lt", "sgt", "capt", "rev",
+	"hon",
+];
+
+/// Finds the word in the list, compared ignoring case.
+#[inline(always)]
+pub(crate) fn find<'a>(list: &[&'a str], word: &[u8]) -> Option<&'a str> {
+	list.iter().cloned().find(|w| match str::from_utf8(word) {
+		Ok(word) => w.chars().flat_map(char::to_lowercase).eq(word.chars().flat_map(char::to_lowercase)),
+		Err(..)  => w.as_bytes().eq_ignore_ascii_case(word),
+	})
+}
+
+/// Whether the word ends a sentence, with a period that's not part of an
+/// abbreviation, or with an exclamation or question mark.
+#[inline(always)]
+pub(crate) fn is_sentence_end(word: &[u8]) -> bool {
+	let mut word = word;
+
+	// Closing quotes and parentheses can follow the end of a sentence.
+	while let Some(close) = ["\"", "'", ")", "]", "”", "’", "»"].iter().find(|close| word.ends_with(close.as_bytes())) {
+		word = &word[.. word.len() - close.len()];
+	}
+
+	match word.split_last() {
+		Some((b'!' | b'?', _)) =>
+			true,
+
+		Some((b'.', rest)) => {
+			let start = rest.iter().position(|&ch| ch.is_ascii_alphanumeric() || !ch.is_ascii()).unwrap_or(rest.len());
+			let rest  = &rest[start ..];
+
+			rest.ends_with(b".") || !(rest.contains(&b'.') || rest.len() == 1 || find(ABBREVIATIONS, rest).is_some())
+		}
+
+		_ =>
+			false
+	}
+}
+
+/// Whether the word ends a title, so the next one starts a subtitle.
+#[inline(always)]
+pub(crate) fn is_subtitle(word: &[u8]) -> bool {
+	matches!(word.last(), Some(b':' | b'?' | b'!')) || word.ends_with("—".as_bytes()) || word == b"-"
+}
+
+/// Whether the word is a valid roman numeral in upper case, `VIII`.
+#[inline(always)]
+pub(crate) fn is_roman(word: &[u8]) -> bool {
+	let thousands = word.iter().take(3).take_while(|&&b| b == b'M').count();
+	let mut rest  = &word[thousands ..];
+
+	for &(one, five, ten) in &[(b'C', b'D', b'M'), (b'X', b'L', b'C'), (b'I', b'V', b'X')] {
+		rest = digit(rest, one, five, ten);
+	}
+
+	!word.is_empty() && rest.is_empty()
+}
+
+/// Strips a single roman digit from the start of the word, if any.
+#[inline(always)]
+fn digit(word: &[u8], one: u8, five: u8, ten: u8) -> &[u8] {
+	if word.starts_with(&[one, ten]) || word.starts_with(&[one, five]) {
+		return &word[2 ..];
+	}
+
+	let word = word.strip_prefix(&[five]).unwrap_or(word);
+	let ones = word.iter().take(3).take_while(|&&b| b == one).count();
+
+	&word[ones ..]
+}
+
+#[cfg(test)]
+mod test {
+	use {Style, Locale};
+	use super::{Rules, is_roman, is_sentence_end};
+
+	#[test]
+	fn sentence_end() {
+		assert!(is_sentence_end(b"no."));
+		assert!(is_sentence_end(b"St."));
+		assert!(is_sentence_end(b"again!"));
+		assert!(!is_sentence_end(b"Dr."));
+		assert!(!is_sentence_end(b"e.g."));
+		assert!(!is_sentence_end(b"J."));
+	}
+
+	#[test]
+	fn roman() {
+		for numeral in &["I", "IV", "VIII", "IX", "XIV", "XL", "XCIX", "MCMLXXXIV", "MMXXVI"] {
+			assert!(is_roman(numeral.as_bytes()), "{}", numeral);
+		}
+
+		for word in &["", "IIII", "VV", "IC", "XM", "viii", "MIXX", "NASA"] {
+			assert!(!is_roman(word.as_bytes()), "{}", word);
+		}
+	}
+
+	#[test]
+	fn minor() {
+		let rules = |style| Rules::new(style, Locale::None);
+
+		assert!(rules(Style::Chicago).is_minor(b"The"));
+		assert!(rules(Style::Chicago).is_minor(b"between"));
+		assert!(!rules(Style::Chicago).is_minor(b"so"));
+		assert!(rules(Style::Apa).is_minor(b"so"));
+		assert!(!rules(Style::Apa).is_minor(b"between"));
+		assert!(rules(Style::Mla).is_minor(b"yet"));
+		assert!(!rules(Style::Ap).is_minor(b"is"));
+
+		assert!(Rules::new(Style::Chicago, Locale::Italian).is_minor(b"della"));
+		assert!(Rules::new(Style::Chicago, Locale::Portuguese).is_minor("ÀS".as_bytes()));
+		assert!(!Rules::new(Style::Chicago, Locale::Portuguese).is_minor(b"the"));
+		assert!(!Rules::new(Style::Chicago, Locale::French).is_minor(b"le"));
+		assert!(!Rules::new(Style::Chicago, Locale::Greek).is_minor(b"the"));
+	}
+}
@@ -0,0 +1,76 @@
+use buffer::Buffer;
+
+/// Prefixes added by eclipsis, the last character is the initial of the word.
+const ECLIPSIS: &[&str] = &["bhf", "bp", "dt", "gc", "mb", "nd", "ng"];
+
+/// Finds the prefix added to a word by an initial mutation, returning the end
+/// of the prefix and the start of the actual word.
+///
+/// Eclipsis is always recognized, in any case, `gcat`, `gCat` and `GCAT`,
+/// and so is a prefixed vowel after a hyphen, `n-athair` and `N-ATHAIR`.
+///
+/// Without a hyphen a prefixed vowel is only recognized when the prefix is in
+/// lower case and the vowel in upper case, `hÉireann`, since `h`, `n` and `t`
+/// are common initials too; in upper case text they can't be told apart, so
+/// `HÉIREANN` is handled like `HATA`.
+#[inline(always)]
+pub fn mutation(word: &str) -> Option<(usize, usize)> {
+	for prefix in ECLIPSIS {
+		let (prefix, initial) = prefix.split_at(prefix.len() - 1);
+
+		if word.get(.. prefix.len()).is_some_and(|start| start.eq_ignore_ascii_case(prefix)) &&
+		   word[prefix.len() ..].get(.. 1).is_some_and(|ch| ch.eq_ignore_ascii_case(initial))
+		{
+			return Some((prefix.len(), prefix.len()));
+		}
+	}
+
+	let mut chars = word.chars();
+
+	match (chars.next(), chars.next(), chars.next()) {
+		(Some('h'), Some(ch), _) | (Some('n'), Some(ch), _) | (Some('t'), Some(ch), _) if ch.is_uppercase() && is_vowel(ch) =>
+			Some((1, 1)),
+
+		(Some('n' | 'N'), Some('-'), Some(ch)) | (Some('t' | 'T'), Some('-'), Some(ch)) if is_vowel(ch) =>
+			Some((1, 2)),
+
+		_ =>
+			None
+	}
+}
+
+/// Lower cases the text starting at the given offset, keeping the initial of
+/// mutated words that's already in upper case, `na hÉireann`, and turning
+/// the prefix to lower case, `NA GCORCAIGH` to `na gCorcaigh`.
+#[inline(always)]
+pub fn lower(result: &mut Buffer<str>, text: &str, offset: usize) {
+	let mut start = !text[.. offset].chars().next_back().is_some_and(char::is_alphanumeric);
+	let mut skip = offset;
+
+	for (i, ch) in text.char_indices() {
+		if i < skip {
+			continue;
+		}
+
+		if start {
+			if let Some((_, initial)) = mutation(&text[i ..]) {
+				if let Some(upper) = text[i + initial ..].chars().next().filter(|ch| ch.is_uppercase()) {
+					skip = i + initial + upper.len_utf8();
+					result.extend(text[i .. i + initial].chars().map(|ch| ch.to_ascii_lowercase()));
+					result.push(upper);
+					start = false;
+
+					continue;
+				}
+			}
+		}
+
+		result.extend(ch.to_lowercase());
+		start = !ch.is_alphanumeric();
+	}
+}
+
+#[inline(always)]
+fn is_vowel(ch: char) -> bool {
+	matches!(ch.to_lowercase().next(), Some('a' | 'e' | 'i' | 'o' | 'u' | 'á' | 'é' | 'í' | 'ó' | 'ú'))
+}
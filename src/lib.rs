@@ -3,12 +3,50 @@
 extern crate test;
 
 use std::borrow::{ToOwned, Cow};
+use std::cmp::Ordering;
+use std::hash::Hasher;
 use std::ops::Deref;
 
+/// How `Casing::camel` cases each word.
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
 pub enum Camel {
+	/// Every word starts in upper case and the rest is kept, `FooBAR`.
 	Upper,
+
+	/// Like `Upper`, but the first word is kept as it is, `fooBAR`.
 	Lower,
+
+	/// Every word starts in upper case and the rest is turned to lower case,
+	/// `FOO_BAR` to `FooBar`.
+	UpperNormalized,
+
+	/// Like `UpperNormalized`, but the first word is turned to lower case
+	/// entirely, `FOO_BAR` to `fooBar`.
+	LowerNormalized,
+}
+
+/// Which character `Casing::capitalized` turns to upper case.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub enum Capital {
+	/// The first letter or digit, leaving any punctuation before it as it is,
+	/// `¿Qué pasa?` and `«Bonjour»`.
+	#[default]
+	Letter,
+
+	/// Strictly the first character, even when it's not a letter.
+	First,
+}
+
+/// How `Casing::fold` folds characters, following CaseFolding.txt.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub enum FoldMode {
+	/// Characters can expand to more than one, `ß` to `ss` and `ﬁ` to `fi`,
+	/// so strings that only differ in case always fold to the same string.
+	#[default]
+	Full,
+
+	/// Each character folds to a single character, keeping the length.
+	Simple,
 }
 
 /// Wrapper for a string separator.
@@ -27,17 +65,17 @@ impl Default for Separator<u8> {
 	}
 }
 
-impl<'a> Default for Separator<&'a [char]> {
+impl Default for Separator<&[char]> {
 	fn default() -> Self {
-		const DEFAULT: &'static [char] = &['-', '_'];
+		const DEFAULT: &[char] = &['-', '_'];
 
 		Separator(DEFAULT)
 	}
 }
 
-impl<'a> Default for Separator<&'a [u8]> {
+impl Default for Separator<&[u8]> {
 	fn default() -> Self {
-		const DEFAULT: &'static [u8] = &[b'-', b'_'];
+		const DEFAULT: &[u8] = b"-_";
 
 		Separator(DEFAULT)
 	}
@@ -51,46 +89,162 @@ impl<T: Copy + Eq> Deref for Separator<T> {
 	}
 }
 
-/// A specific locale.
-#[derive(Eq, PartialEq, Copy, Clone, Debug)]
-pub enum Locale {
-	None,
-}
-
-impl Default for Locale {
-	fn default() -> Self {
-		Locale::None
-	}
-}
-
 /// Trait with casing extensions.
 pub trait Casing: ToOwned {
 	type Character: Copy + Eq;
 
 	/// Turns `Self` to upper case avoiding allocations if nothing would change.
-	fn upper(&self, Locale) -> Cow<Self>;
+	fn upper(&self, locale: Locale) -> Cow<'_, Self>;
 
 	/// Turns `Self` to lower case avoiding allocations if nothing would change.
-	fn lower(&self, Locale) -> Cow<Self>;
-
-	/// Turns `Self` to its capitalized version, turning the first character to
-	/// upper case and the rest to lower case.
-	fn capitalized(&self, Locale) -> Cow<Self>;
-
-	/// Turns `Self` to camel case using the passed `Separator` to know which
-	/// symbols should mark a new word.
-	fn camel(&self, separators: Separator<&[Self::Character]>, mode: Camel, Locale) -> Cow<Self>;
-
-	/// Turns `Self` to a case separated by the given separator, using
+	fn lower(&self, locale: Locale) -> Cow<'_, Self>;
+
+	/// Folds the case of `Self`, giving the same string for strings that only
+	/// differ in case, avoiding allocations if nothing would change.
+	///
+	/// Unlike lower casing, `ﬁ` and `ß` fold to `fi` and `ss`, and Cherokee
+	/// folds to upper case; only Turkic locales change the folding. The
+	/// foldings follow CaseFolding.txt from Unicode 17.0.0, the version of the
+	/// case mappings in `std`.
+	///
+	/// Bytes are folded, compared and hashed like `str` where they're valid
+	/// UTF-8, following the locale, and any invalid byte is kept as it is.
+	fn fold(&self, mode: FoldMode, locale: Locale) -> Cow<'_, Self>;
+
+	/// Checks if `Self` and `other` are the same ignoring case, comparing
+	/// their full case folding without allocating.
+	fn eq_ignore_case(&self, other: &Self, locale: Locale) -> bool;
+
+	/// Compares `Self` and `other` ignoring case, ordering their full case
+	/// folding by code point without allocating.
+	fn cmp_ignore_case(&self, other: &Self, locale: Locale) -> Ordering;
+
+	/// Feeds the full case folding of `Self` into the `Hasher` without
+	/// allocating, strings equal ignoring case hash the same.
+	fn hash_folded<H: Hasher>(&self, state: &mut H, locale: Locale);
+
+	/// Turns `Self` to its capitalized version, turning the first letter to
+	/// title case and the rest to lower case, `Capital` picks which one is the
+	/// first letter.
+	fn capitalized(&self, capital: Capital, locale: Locale) -> Cow<'_, Self>;
+
+	/// Turns `Self` to camel case using the passed `Boundary` to know where a
+	/// new word starts.
+	fn camel(&self, boundary: Boundary<Self::Character>, mode: Camel, locale: Locale) -> Cow<'_, Self>;
+
+	/// Turns `Self` to a case separated by the given separator, using the
+	/// passed `Boundary` to know where a new word starts, using
 	/// `Separator('_')` would turn to snake case, using `Separator('-')` would
 	/// turn to dashed case.
-	fn separated(&self, separator: Separator<Self::Character>, Locale) -> Cow<Self>;
+	fn separated(&self, boundary: Boundary<Self::Character>, separator: Separator<Self::Character>, locale: Locale) -> Cow<'_, Self>;
+
+	/// Turns `Self` to title case, turning the first letter of every word to
+	/// title case and the rest to lower case, keeping anything between words
+	/// as it is.
+	fn title(&self, locale: Locale) -> Cow<'_, Self>;
 
 	/// Turns `Self` to header case, where each word is separated by `'-'` and
 	/// starts with an upper case character. Upper case characters after the
 	/// first are not lower cased.
-	fn header(&self, Locale) -> Cow<Self>;
+	fn header(&self, locale: Locale) -> Cow<'_, Self>;
+
+	/// Turns `Self` to train case using the passed `Boundary` to know where a
+	/// new word starts, where each word is separated by the passed `Separator`
+	/// and starts with an upper case character; `Body` tells what happens to
+	/// the rest of each word, known acronyms are cased following their style.
+	fn train(&self, boundary: Boundary<Self::Character>, separator: Separator<Self::Character>, body: Body, locale: Locale) -> Cow<'_, Self>;
+
+	/// Turns `Self` to title case following the rules of the language of the
+	/// `Locale`, or of the given `Style` for English, `Locale::None`, keeping all
+	/// the whitespace and punctuation as it is.
+	fn headline(&self, style: Style, locale: Locale) -> Cow<'_, Self>;
+
+	/// Turns `Self` to sentence case, where only the first letter of each
+	/// sentence is in upper case, keeping all the whitespace and punctuation
+	/// as it is.
+	///
+	/// Words in the `preserve` list are spelled like in the list, `GitHub`, and
+	/// acronyms or words with mixed case are kept as they are, `NASA` and
+	/// `MySQL`, like the English pronoun `I` for `Locale::None`.
+	fn sentence(&self, preserve: &[&str], locale: Locale) -> Cow<'_, Self>;
+
+	/// Turns `Self` to a slug for URLs, made only of lower case ASCII letters
+	/// and digits, transliterating Latin, Greek and Cyrillic letters and
+	/// dropping anything else, `Crème Brûlée` to `creme-brulee`.
+	///
+	/// Apostrophes don't break words, `don't` to `dont`. The `[u8]`
+	/// implementation transliterates valid UTF-8 like `str`, and otherwise
+	/// drops any non ASCII byte.
+	fn slug(&self, slug: Slug<'_, Self::Character>) -> Cow<'_, Self>;
+
+	/// Turns `Self` to the given `Case` avoiding allocations if nothing would
+	/// change.
+	fn to_case(&self, case: Case, locale: Locale) -> Cow<'_, Self>;
+
+	/// Checks if `Self` already follows the given `Case`, without allocating.
+	///
+	/// Strings without any word, like the empty string, follow no case.
+	fn is_case(&self, case: Case) -> bool;
+
+	/// Checks if `Self` is in `snake_case`.
+	fn is_snake(&self) -> bool {
+		self.is_case(Case::Snake)
+	}
+
+	/// Checks if `Self` is in `kebab-case`.
+	fn is_kebab(&self) -> bool {
+		self.is_case(Case::Kebab)
+	}
+
+	/// Checks if `Self` is in `SCREAMING_SNAKE_CASE`.
+	fn is_screaming_snake(&self) -> bool {
+		self.is_case(Case::ScreamingSnake)
+	}
+
+	/// Checks if `Self` is in `camelCase`.
+	fn is_camel(&self) -> bool {
+		self.is_case(Case::Camel)
+	}
+
+	/// Checks if `Self` is in `PascalCase`.
+	fn is_pascal(&self) -> bool {
+		self.is_case(Case::Pascal)
+	}
+
+	/// Returns all the cases `Self` already follows, a single word like `foo`
+	/// follows many at once, and a string without any word follows none.
+	fn detect_case(&self) -> Cases;
+
+	/// Splits `Self` into words following the passed `Boundary`, the default
+	/// one recognizes the usual styles (`fooBar`, `FOO_BAR`, `foo-bar`,
+	/// `XMLHttpRequest`). Each word is borrowed from `Self`.
+	fn words<'a>(&'a self, boundary: Boundary<'a, Self::Character>) -> Words<'a, Self>;
 }
 
+mod locale;
+pub use locale::{Locale, ParseLocaleError};
+
+mod buffer;
+mod tables;
+mod combining;
+mod greek;
+mod lithuanian;
+mod irish;
+mod fold;
+mod words;
+pub use words::{Boundary, Symbols, Acronyms, Initialism, Words, Word, Kind};
+
+mod headline;
+pub use headline::Style;
+
+mod slug;
+pub use slug::Slug;
+
+mod case;
+pub use case::{Case, Cases, ParseCaseError};
+
+mod converter;
+pub use converter::{Converter, Body};
+
 mod unicode;
 mod ascii;
@@ -0,0 +1,62 @@
+use tables::SOFT_DOTTED;
+use combining;
+
+/// The combining class of combining marks placed above the base character.
+const ABOVE: u8 = 230;
+
+/// The lower case version of the character when it differs from the default
+/// one, keeping the dot of `i` and `j` when there are other accents on them.
+///
+/// The text is whatever follows the character.
+#[inline(always)]
+pub fn lower(ch: char, text: &str) -> Option<&'static str> {
+	match ch {
+		'I' if is_more_above(text) => Some("i\u{307}"),
+		'J' if is_more_above(text) => Some("j\u{307}"),
+		'Į' if is_more_above(text) => Some("į\u{307}"),
+		'Ì'                        => Some("i\u{307}\u{300}"),
+		'Í'                        => Some("i\u{307}\u{301}"),
+		'Ĩ'                        => Some("i\u{307}\u{303}"),
+		_                          => None,
+	}
+}
+
+/// Whether a combining dot above is redundant and has to be dropped when
+/// upper casing, since it follows a soft dotted character.
+///
+/// The text is whatever precedes the dot.
+#[inline(always)]
+pub fn is_after_soft_dotted(text: &str) -> bool {
+	for ch in text.chars().rev() {
+		if is_soft_dotted(ch) {
+			return true;
+		}
+
+		match combining::class(ch) {
+			0 | ABOVE => return false,
+			_         => (),
+		}
+	}
+
+	false
+}
+
+/// Whether the text starts with a combining mark above, possibly after other
+/// combining marks.
+#[inline(always)]
+fn is_more_above(text: &str) -> bool {
+	for ch in text.chars() {
+		match combining::class(ch) {
+			ABOVE => return true,
+			0     => return false,
+			_     => (),
+		}
+	}
+
+	false
+}
+
+#[inline(always)]
+fn is_soft_dotted(ch: char) -> bool {
+	SOFT_DOTTED.binary_search_by(|&(start, end)| combining::range(ch, start, end)).is_ok()
+}
@@ -0,0 +1,189 @@
+use std::env;
+use std::fmt;
+use std::error::Error;
+use std::str::FromStr;
+
+/// A specific locale, changing how some characters are cased.
+///
+/// The `[u8]` implementation follows the locale when folding, comparing,
+/// hashing and turning valid UTF-8 to slugs, and otherwise only handles ASCII
+/// and ignores the locale.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub enum Locale {
+	/// The default Unicode casing rules.
+	#[default]
+	None,
+
+	/// Turkish, `i` upper cases to `İ` and `I` lower cases to `ı`.
+	Turkish,
+
+	/// Azerbaijani, same as Turkish.
+	Azerbaijani,
+
+	/// Greek, accents are dropped when upper casing, `Μάιος` to `ΜΑΪΟΣ`.
+	Greek,
+
+	/// Lithuanian, the dot of `i` and `j` is kept as U+0307 when lower casing
+	/// them with other accents on top, `Ì` to `i̇̀`.
+	Lithuanian,
+
+	/// Dutch, the `ij` digraph is capitalized as a whole, `IJsselmeer`.
+	Dutch,
+
+	/// German, `ß` upper cases to `ẞ` instead of `SS`, so upper casing keeps
+	/// the length and can be reversed.
+	German,
+
+	/// Irish, the prefix of words with an initial mutation stays in lower case
+	/// when capitalizing, `nAthair` and `hÉireann`.
+	///
+	/// In upper case text only eclipsis and prefixes with a hyphen are told
+	/// apart from initials, `GCORCAIGH` to `gCorcaigh` but `HÉIREANN` to
+	/// `Héireann`.
+	Irish,
+
+	/// French, headlines only capitalize the first word.
+	French,
+
+	/// Spanish, headlines only capitalize the first word.
+	Spanish,
+
+	/// Italian, headlines keep Italian articles and prepositions in lower case.
+	Italian,
+
+	/// Portuguese, headlines keep Portuguese articles and prepositions in lower
+	/// case.
+	Portuguese,
+}
+
+impl Locale {
+	/// Whether the locale distinguishes dotted and dotless `i`.
+	#[inline(always)]
+	pub(crate) fn is_turkic(self) -> bool {
+		self == Locale::Turkish || self == Locale::Azerbaijani
+	}
+
+	/// Picks the locale from the environment, looking at `LC_ALL`, `LC_CTYPE`
+	/// and `LANG` in order, like the C library does.
+	///
+	/// Falls back to `Locale::None` when none is set, or when it can't be
+	/// parsed.
+	pub fn from_env() -> Self {
+		["LC_ALL", "LC_CTYPE", "LANG"].iter()
+			.filter_map(|name| env::var(name).ok())
+			.find(|value| !value.is_empty())
+			.map_or(Locale::None, |value| Locale::from_posix(&value))
+	}
+
+	/// Parses a POSIX locale name, `tr_TR.UTF-8@euro`.
+	fn from_posix(value: &str) -> Self {
+		let name = value.split(['.', '@']).next().unwrap_or("");
+
+		match name {
+			"C" | "POSIX" => Locale::None,
+			name          => name.parse().unwrap_or(Locale::None),
+		}
+	}
+}
+
+/// Error returned when parsing a malformed language tag.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct ParseLocaleError(());
+
+impl fmt::Display for ParseLocaleError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("malformed language tag")
+	}
+}
+
+impl Error for ParseLocaleError { }
+
+/// Parses a BCP 47 language tag, `tr-TR`, `az-Latn` or `el`; subtags can be
+/// separated by underscores as well.
+///
+/// Only the language and the script matter, any other subtag is ignored and
+/// languages with no special rules fall back to `Locale::None`.
+impl FromStr for Locale {
+	type Err = ParseLocaleError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut subtags = s.split(['-', '_']);
+		let language    = subtags.next().unwrap_or("");
+
+		if language.len() < 2 || language.len() > 8 || !language.bytes().all(|b| b.is_ascii_alphabetic()) {
+			return Err(ParseLocaleError(()));
+		}
+
+		let mut script = None;
+
+		for (i, subtag) in subtags.enumerate() {
+			if subtag.is_empty() || subtag.len() > 8 || !subtag.bytes().all(|b| b.is_ascii_alphanumeric()) {
+				return Err(ParseLocaleError(()));
+			}
+
+			if i == 0 && subtag.len() == 4 && subtag.bytes().all(|b| b.is_ascii_alphabetic()) {
+				script = Some(subtag);
+			}
+		}
+
+		// The dotted and dotless `i` only exist in the Latin script.
+		let latin = script.is_none_or(|script| script.eq_ignore_ascii_case("latn"));
+
+		Ok(match &*language.to_ascii_lowercase() {
+			"tr" | "tur" if latin         => Locale::Turkish,
+			"az" | "aze" if latin         => Locale::Azerbaijani,
+			"el" | "ell" | "gre"          => Locale::Greek,
+			"lt" | "lit"                  => Locale::Lithuanian,
+			"nl" | "nld" | "dut"          => Locale::Dutch,
+			"de" | "deu" | "ger"          => Locale::German,
+			"ga" | "gle"                  => Locale::Irish,
+			"fr" | "fra" | "fre"          => Locale::French,
+			"es" | "spa"                  => Locale::Spanish,
+			"it" | "ita"                  => Locale::Italian,
+			"pt" | "por"                  => Locale::Portuguese,
+			_                             => Locale::None,
+		})
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use Locale;
+
+	#[test]
+	fn from_str() {
+		assert_eq!(Ok(Locale::Turkish), "tr".parse());
+		assert_eq!(Ok(Locale::Turkish), "tr-TR".parse());
+		assert_eq!(Ok(Locale::Turkish), "TR_tr".parse());
+		assert_eq!(Ok(Locale::Azerbaijani), "az-Latn".parse());
+		assert_eq!(Ok(Locale::Azerbaijani), "az-Latn-AZ".parse());
+		assert_eq!(Ok(Locale::None), "az-Cyrl".parse());
+		assert_eq!(Ok(Locale::Greek), "el".parse());
+		assert_eq!(Ok(Locale::Greek), "el-GR".parse());
+		assert_eq!(Ok(Locale::Lithuanian), "lt-LT".parse());
+		assert_eq!(Ok(Locale::Dutch), "nl-BE".parse());
+		assert_eq!(Ok(Locale::German), "de-CH".parse());
+		assert_eq!(Ok(Locale::Irish), "ga-IE".parse());
+		assert_eq!(Ok(Locale::French), "fr-CA".parse());
+		assert_eq!(Ok(Locale::Spanish), "es-419".parse());
+		assert_eq!(Ok(Locale::Portuguese), "pt-BR".parse());
+		assert_eq!(Ok(Locale::None), "en-US".parse());
+		assert_eq!(Ok(Locale::None), "und".parse());
+
+		assert!("".parse::<Locale>().is_err());
+		assert!("t".parse::<Locale>().is_err());
+		assert!("tr-".parse::<Locale>().is_err());
+		assert!("tr TR".parse::<Locale>().is_err());
+		assert!("*".parse::<Locale>().is_err());
+	}
+
+	#[test]
+	fn from_posix() {
+		assert_eq!(Locale::Turkish, Locale::from_posix("tr_TR.UTF-8"));
+		assert_eq!(Locale::Greek, Locale::from_posix("el_GR.ISO-8859-7@euro"));
+		assert_eq!(Locale::Dutch, Locale::from_posix("nl_NL@euro"));
+		assert_eq!(Locale::None, Locale::from_posix("C.UTF-8"));
+		assert_eq!(Locale::None, Locale::from_posix("POSIX"));
+		assert_eq!(Locale::None, Locale::from_posix("???"));
+	}
+}
@@ -0,0 +1,87 @@
+use std::mem;
+use std::iter::FlatMap;
+use std::str::Chars;
+use std::char::ToLowercase;
+use tables::ASCII;
+
+/// Options for `Casing::slug`.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Slug<'a, T: Copy + Eq + 'a> {
+	/// What separates words, it should be ASCII to keep the slug safe in URLs.
+	pub separator: T,
+
+	/// The maximum length of the slug in bytes, words that don't fit are left
+	/// out and a first word that doesn't fit is cut.
+	pub length: Option<usize>,
+
+	/// Words that are left out, `a` and `the`, compared after transliterating
+	/// them.
+	pub stopwords: &'a [&'a str],
+}
+
+impl Default for Slug<'_, char> {
+	fn default() -> Self {
+		Slug {
+			separator: '-',
+			length:    None,
+			stopwords: &[],
+		}
+	}
+}
+
+impl Default for Slug<'_, u8> {
+	fn default() -> Self {
+		Slug {
+			separator: b'-',
+			length:    None,
+			stopwords: &[],
+		}
+	}
+}
+
+/// Iterator over a word transliterated to lower case ASCII letters and digits,
+/// anything that can't be transliterated is dropped.
+pub struct Transliterate<'a> {
+	chars:    FlatMap<Chars<'a>, ToLowercase, fn(char) -> ToLowercase>,
+	previous: char,
+	pending:  Chars<'static>,
+}
+
+impl<'a> Transliterate<'a> {
+	#[inline(always)]
+	pub fn new(word: &'a str) -> Self {
+		Transliterate {
+			chars:    word.chars().flat_map(char::to_lowercase as fn(char) -> ToLowercase),
+			previous: '\0',
+			pending:  "".chars(),
+		}
+	}
+}
+
+impl<'a> Iterator for Transliterate<'a> {
+	type Item = char;
+
+	fn next(&mut self) -> Option<char> {
+		loop {
+			if let Some(ch) = self.pending.next() {
+				return Some(ch);
+			}
+
+			let ch       = self.chars.next()?;
+			let previous = mem::replace(&mut self.previous, ch);
+
+			if ch.is_ascii_alphanumeric() {
+				return Some(ch);
+			}
+
+			// The `ου` digraph is a single vowel, `Κούκος` to `koukos`.
+			if previous == 'ο' && (ch == 'υ' || ch == 'ύ') {
+				return Some('u');
+			}
+
+			if let Ok(index) = ASCII.binary_search_by_key(&ch, |&(ch, _)| ch) {
+				self.pending = ASCII[index].1.chars();
+			}
+		}
+	}
+}
@@ -1,11 +1,30 @@
 use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::hash::Hasher;
 use std::str::CharIndices;
-use {Casing, Locale, Separator};
+use {Casing, Converter, Camel, Capital, FoldMode, Case, Cases, Body, Locale, Style, Separator, Boundary, Symbols, Slug};
+use buffer::Buffer;
+use tables::TITLECASE;
+use combining;
+use headline::{self, Rules};
+use greek;
+use lithuanian;
+use irish;
+use fold::{self, Folded};
+use slug::Transliterate;
+use words::{Words, Word, Kind, Pattern, Acronyms};
 
 impl Casing for str {
 	type Character = char;
 
-	fn upper(&self, _locale: Locale) -> Cow<Self> {
+	fn upper(&self, locale: Locale) -> Cow<'_, Self> {
+		if locale != Locale::None {
+			let mut result = Buffer::<str>::new(self);
+			push_upper(&mut result, self, locale);
+
+			return result.finish();
+		}
+
 		let mut chars = self.char_indices();
 
 		while let Some((start, ch)) = chars.next() {
@@ -51,7 +70,14 @@ impl Casing for str {
 		}
 	}
 
-	fn lower(&self, _locale: Locale) -> Cow<Self> {
+	fn lower(&self, locale: Locale) -> Cow<'_, Self> {
+		if locale != Locale::None {
+			let mut result = Buffer::<str>::new(self);
+			push_lower(&mut result, self, 0, locale);
+
+			return result.finish();
+		}
+
 		let mut chars = self.char_indices();
 
 		while let Some((start, ch)) = chars.next() {
@@ -67,7 +93,7 @@ impl Casing for str {
 		fn owned(this: &str, chars: CharIndices, (start, ch): (usize, char)) -> String {
 			let mut result = String::with_capacity(this.len());
 			result.push_str(&this[.. start]);
-			result.extend(ch.to_lowercase());
+			lowercase(&mut result, this, start, ch);
 
 			// The already lower case starting offset, if any.
 			let mut leftover = None;
@@ -81,7 +107,7 @@ impl Casing for str {
 						result.push_str(&this[offset .. i]);
 					}
 
-					result.extend(ch.to_lowercase());
+					lowercase(&mut result, this, i, ch);
 				}
 				else if leftover.is_none() {
 					leftover = Some(i);
@@ -97,12 +123,74 @@ impl Casing for str {
 		}
 	}
 
-	fn capitalized(&self, _locale: Locale) -> Cow<Self> {
+	fn fold(&self, mode: FoldMode, locale: Locale) -> Cow<'_, Self> {
+		let mut result = Buffer::<str>::new(self);
+
+		for ch in self.chars() {
+			match fold::full(ch, locale).filter(|_| mode == FoldMode::Full) {
+				Some(folded) => result.push_str(folded),
+				None         => result.push(fold::simple(ch, locale)),
+			}
+		}
+
+		result.finish()
+	}
+
+	fn eq_ignore_case(&self, other: &Self, locale: Locale) -> bool {
+		// ASCII only folds to ASCII, outside of Turkic locales.
+		if !locale.is_turkic() && self.is_ascii() && other.is_ascii() {
+			return self.eq_ignore_ascii_case(other);
+		}
+
+		Folded::new(self, locale).eq(Folded::new(other, locale))
+	}
+
+	fn cmp_ignore_case(&self, other: &Self, locale: Locale) -> Ordering {
+		Folded::new(self, locale).cmp(Folded::new(other, locale))
+	}
+
+	fn hash_folded<H: Hasher>(&self, state: &mut H, locale: Locale) {
+		// Characters are hashed in chunks to avoid a call for each of them.
+		let mut chunk  = [0; 64];
+		let mut length = 0;
+
+		for ch in Folded::new(self, locale) {
+			if length + ch.len_utf8() > chunk.len() {
+				state.write(&chunk[.. length]);
+				length = 0;
+			}
+
+			length += ch.encode_utf8(&mut chunk[length ..]).len();
+		}
+
+		state.write(&chunk[.. length]);
+		state.write_u8(0xff);
+	}
+
+	fn capitalized(&self, capital: Capital, locale: Locale) -> Cow<'_, Self> {
+		// Anything before the first letter or digit is left as it is.
+		let start = match capital {
+			Capital::Letter => self.find(char::is_alphanumeric).unwrap_or(self.len()),
+			Capital::First  => 0,
+		};
+
+		if locale != Locale::None {
+			let mut result = Buffer::<str>::new(self);
+			result.push_str(&self[.. start]);
+			push_title(&mut result, &self[start ..], locale);
+
+			return result.finish();
+		}
+
 		let mut chars = self.char_indices();
 
+		while chars.offset() < start {
+			chars.next();
+		}
+
 		if let Some((start, ch)) = chars.next() {
-			// If the first letter is already uppercase we don't need to do anything.
-			if !ch.is_uppercase() && ch.is_alphabetic() {
+			// If the first letter is already title case we don't need to do anything.
+			if !is_titlecase(ch) {
 				return Cow::Owned(owned(self, chars, (start, ch), true));
 			}
 
@@ -122,10 +210,13 @@ impl Casing for str {
 			result.push_str(&this[.. start]);
 
 			if upcase {
-				result.extend(ch.to_uppercase());
+				match titlecase(ch) {
+					Some(title) => result.push_str(title),
+					None        => result.extend(ch.to_uppercase()),
+				}
 			}
 			else {
-				result.extend(ch.to_lowercase());
+				lowercase(&mut result, this, start, ch);
 			}
 
 			// The already lower case starting offset, if any.
@@ -140,7 +231,7 @@ impl Casing for str {
 						result.push_str(&this[offset .. i]);
 					}
 
-					result.extend(ch.to_lowercase());
+					lowercase(&mut result, this, i, ch);
 				}
 				else if leftover.is_none() {
 					leftover = Some(i);
@@ -156,190 +247,663 @@ impl Casing for str {
 		}
 	}
 
-	fn camel(&self, separator: Separator<&[char]>, mode: super::Camel, _locale: Locale) -> Cow<Self> {
-		let mut chars    = self.char_indices();
-		let mut new_word = mode == super::Camel::Upper;
+	fn camel(&self, boundary: Boundary<char>, mode: Camel, locale: Locale) -> Cow<'_, Self> {
+		let (first, rest) = match mode {
+			Camel::Upper           => (Pattern::Capital, Pattern::Capital),
+			Camel::Lower           => (Pattern::Keep, Pattern::Capital),
+			Camel::UpperNormalized => (Pattern::Title, Pattern::Title),
+			Camel::LowerNormalized => (Pattern::Lower, Pattern::Title),
+		};
 
-		while let Some((start, ch)) = chars.next() {
-			if new_word && !ch.is_uppercase() && ch.is_alphabetic() {
-				return Cow::Owned(owned(self, separator, chars, (start, ch), new_word));
-			}
-			else if separator.0.iter().any(|&c| ch == c) {
-				return Cow::Owned(owned(self, separator, chars, (start, ch), true));
-			}
-			else {
-				new_word = false;
-			}
-		}
+		join(self, Words::new(self, boundary), None, first, rest, locale)
+	}
 
-		return Cow::Borrowed(self);
+	fn separated(&self, boundary: Boundary<char>, separator: Separator<char>, locale: Locale) -> Cow<'_, Self> {
+		join(self, Words::new(self, boundary), Some(separator.0), Pattern::Lower, Pattern::Lower, locale)
+	}
 
-		#[inline(always)]
-		fn owned(this: &str, separator: Separator<&[char]>, chars: CharIndices, (start, ch): (usize, char), mut new_word: bool) -> String {
-			let mut result = String::with_capacity(this.len());
-			result.push_str(&this[.. start]);
+	fn title(&self, locale: Locale) -> Cow<'_, Self> {
+		let mut result = Buffer::<str>::new(self);
 
-			if separator.0.iter().all(|&c| ch != c) {
-				if new_word {
-					result.extend(ch.to_uppercase());
+		// The start of the current word, if any, and the end of the last one.
+		let mut start = None;
+		let mut end   = 0;
+
+		for (i, ch) in self.char_indices() {
+			// Apostrophes and combining marks don't break a word, `don't`.
+			let inside = ch.is_alphanumeric() || (start.is_some() &&
+				(ch == '\'' || ch == '’' || combining::is_mark(ch)));
+
+			match (start, inside) {
+				(None, true) => {
+					result.push_str(&self[end .. i]);
+					start = Some(i);
 				}
-				else {
-					result.push(ch);
+
+				(Some(offset), false) => {
+					push_title(&mut result, &self[offset .. i], locale);
+					start = None;
+					end   = i;
 				}
+
+				_ => ()
 			}
+		}
 
-			// The already properly cased starting offset, if any.
-			let mut leftover = None;
-			        new_word = separator.0.iter().any(|&c| ch == c);
+		match start {
+			Some(offset) => push_title(&mut result, &self[offset ..], locale),
+			None         => result.push_str(&self[end ..]),
+		}
 
-			for (i, ch) in chars {
-				if new_word && !ch.is_uppercase() && ch.is_alphabetic() {
-					new_word = false;
+		result.finish()
+	}
 
-					if let Some(offset) = leftover.take() {
-						result.push_str(&this[offset .. i]);
-					}
+	fn train(&self, boundary: Boundary<char>, separator: Separator<char>, body: Body, locale: Locale) -> Cow<'_, Self> {
+		let pattern = match body {
+			Body::Keep  => Pattern::Capital,
+			Body::Lower => Pattern::Title,
+		};
 
-					result.extend(ch.to_uppercase());
-				}
-				else if separator.0.iter().any(|&c| ch == c) {
-					new_word = true;
+		join(self, Words::new(self, boundary), Some(separator.0), pattern, pattern, locale)
+	}
 
-					if let Some(offset) = leftover.take() {
-						result.push_str(&this[offset .. i]);
-					}
-				}
-				else {
-					if leftover.is_none() {
-						leftover = Some(i);
+	fn header(&self, locale: Locale) -> Cow<'_, Self> {
+		join(self, Words::new(self, Boundary::only(&['-'])), Some('-'), Pattern::Capital, Pattern::Capital, locale)
+	}
+
+	fn headline(&self, style: Style, locale: Locale) -> Cow<'_, Self> {
+		let mut result = Buffer::<str>::new(self);
+
+		// Words in upper case are only kept as they are when there's some lower
+		// case around them, otherwise the whole text is shouting.
+		let shouting = !self.chars().any(char::is_lowercase);
+		let mut rules = Rules::new(style, locale);
+
+		// Capitalized words can only be told apart as proper nouns when the
+		// text isn't shouting nor in title case already.
+		if let Rules::Sentence { ref mut proper } = rules {
+			let (words, capitalized) = self.split_whitespace()
+				.filter_map(|word| word.chars().find(|ch| ch.is_alphanumeric()))
+				.fold((0, 0), |(words, capitalized), ch| (words + 1, capitalized + ch.is_uppercase() as usize));
+
+			*proper = !shouting && capitalized * 2 <= words;
+		}
+
+		let trimmed = self.trim_end();
+		let last    = trimmed.len() - trimmed.rsplit(char::is_whitespace).next().map_or(0, str::len);
+
+		let mut offset = 0;
+		let mut first  = true;
+
+		for piece in self.split_inclusive(char::is_whitespace) {
+			let word = piece.trim_end_matches(char::is_whitespace);
+
+			if !word.is_empty() {
+				let parts = word.split('-').count();
+
+				// Each part of a compound is capitalized like a word of its own.
+				for (i, part) in word.split('-').enumerate() {
+					if i > 0 {
+						result.push('-');
 					}
 
-					new_word = false;
+					push_headline(&mut result, part, &rules, first && i == 0, offset == last && i == parts - 1, shouting, locale);
 				}
-			}
 
-			// Append any leftover upper case characters.
-			if let Some(offset) = leftover.take() {
-				result.push_str(&this[offset ..]);
+				first = headline::is_subtitle(word.as_bytes());
 			}
 
-			result
+			result.push_str(&piece[word.len() ..]);
+			offset += piece.len();
 		}
+
+		result.finish()
 	}
 
-	fn separated(&self, separator: Separator<char>, _locale: Locale) -> Cow<Self> {
-		let mut chars = self.char_indices();
+	fn sentence(&self, preserve: &[&str], locale: Locale) -> Cow<'_, Self> {
+		let mut result = Buffer::<str>::new(self);
 
-		while let Some((start, ch)) = chars.next() {
-			if ch != separator.0 && !ch.is_lowercase() {
-				return Cow::Owned(owned(self, separator, chars, (start, ch)));
+		// Words in upper case are only kept as acronyms when there's some lower
+		// case around them, otherwise the whole text is shouting.
+		let shouting = !self.chars().any(char::is_lowercase);
+		let mut start = true;
+
+		for piece in self.split_inclusive(char::is_whitespace) {
+			let word = piece.trim_end_matches(char::is_whitespace);
+
+			// Each part of compounds and contractions is checked on its own.
+			for (i, part) in word.split_inclusive(['-', '/', '\'', '’']).enumerate() {
+				push_sentence(&mut result, part, preserve, start && i == 0, shouting, locale);
 			}
+
+			if !word.is_empty() {
+				start = headline::is_sentence_end(word.as_bytes());
+			}
+
+			result.push_str(&piece[word.len() ..]);
 		}
 
-		return Cow::Borrowed(self);
+		result.finish()
+	}
 
-		#[inline(always)]
-		fn owned(this: &str, separator: Separator<char>, chars: CharIndices, (start, ch): (usize, char)) -> String {
-			let mut result = String::with_capacity(this.len());
-			result.push_str(&this[.. start]);
-			result.push(separator.0);
+	fn slug(&self, slug: Slug<'_, char>) -> Cow<'_, Self> {
+		let mut result = Buffer::<str>::new(self);
+		let mut length = 0;
+		let     limit  = slug.length.unwrap_or(usize::MAX);
 
-			if ch.is_alphabetic() {
-				result.extend(ch.to_lowercase());
+		// Apostrophes and combining marks don't break a word, `don't`.
+		for word in self.split(|ch: char| !(ch.is_alphanumeric() || ch == '\'' || ch == '’' || combining::is_mark(ch))) {
+			let size = Transliterate::new(word).count();
+
+			if size == 0 || slug.is_stopword(word) {
+				continue;
 			}
 
-			// The already lower case starting offset, if any.
-			let mut leftover = None;
+			let separator = if length != 0 { slug.separator.len_utf8() } else { 0 };
 
-			for (i, ch) in chars {
-				if ch != separator.0 && !ch.is_lowercase() {
-					if let Some(offset) = leftover.take() {
-						result.push_str(&this[offset .. i]);
-					}
+			if length + separator + size > limit {
+				// A first word that's too long is cut, anything else is left out.
+				if length == 0 {
+					result.extend(Transliterate::new(word).take(limit));
+				}
 
-					result.push(separator.0);
+				break;
+			}
 
-					if ch.is_alphabetic() {
-						result.extend(ch.to_lowercase());
-					}
-				}
-				else if leftover.is_none() {
-					leftover = Some(i);
+			if separator != 0 {
+				result.push(slug.separator);
+			}
+
+			result.extend(Transliterate::new(word));
+			length += separator + size;
+		}
+
+		result.finish()
+	}
+
+	fn to_case(&self, case: Case, locale: Locale) -> Cow<'_, Self> {
+		let (first, rest, separator) = case.patterns();
+
+		join(self, Words::new(self, Default::default()), separator.map(char::from), first, rest, locale)
+	}
+
+	fn is_case(&self, case: Case) -> bool {
+		let (first, rest, separator) = case.patterns();
+		let words = Words::new(self, Default::default());
+
+		// Nothing follows a case without any word.
+		words.clone().next().is_some() && check(self, words, separator.map(char::from), first, rest, Locale::None)
+	}
+
+	fn detect_case(&self) -> Cases {
+		Case::ALL.iter().cloned().filter(|&case| self.is_case(case)).collect()
+	}
+
+	fn words<'a>(&'a self, boundary: Boundary<'a, Self::Character>) -> Words<'a, Self> {
+		Words::new(self, boundary)
+	}
+}
+
+impl<'a> Converter<'a, char> {
+	/// Converts the string avoiding allocations if nothing would change.
+	pub fn convert<'b>(&self, string: &'b str) -> Cow<'b, str> {
+		let (first, rest, separator) = self.patterns();
+
+		join(string, Words::new(string, self.boundary), separator, first, rest, self.locale)
+	}
+}
+
+impl<'a> Slug<'a, char> {
+	/// Whether the word is a stop word, compared after transliterating both.
+	#[inline(always)]
+	fn is_stopword(&self, word: &str) -> bool {
+		self.stopwords.iter().any(|stop| Transliterate::new(stop).eq(Transliterate::new(word)))
+	}
+}
+
+impl<'a> Words<'a, str> {
+	#[inline(always)]
+	fn is_separator(&self, ch: char) -> bool {
+		self.boundary.separators.contains(&ch) || match self.boundary.symbols {
+			Symbols::Separate             => !ch.is_alphanumeric() && !combining::is_mark(ch),
+			Symbols::Drop | Symbols::Word => ch.is_whitespace(),
+			Symbols::Attach               => false,
+		}
+	}
+
+	#[inline(always)]
+	fn is_symbol(&self, ch: char) -> bool {
+		matches!(self.boundary.symbols, Symbols::Drop | Symbols::Word) && is_symbol(ch)
+	}
+}
+
+impl<'a> Iterator for Words<'a, str> {
+	type Item = Word<'a, str>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some(word) = self.acronym() {
+			return Some(word);
+		}
+
+		let input     = &self.input[self.offset ..];
+		let mut chars = input.char_indices().peekable();
+
+		// Skip any leading separator, along with any combining mark on it.
+		let mut skipped = false;
+
+		let (start, mut previous) = loop {
+			match chars.next() {
+				Some((_, ch)) if self.is_separator(ch) => skipped = true,
+				Some((_, ch)) if self.boundary.symbols == Symbols::Drop && self.is_symbol(ch) => skipped = true,
+				Some((_, ch)) if skipped && combining::is_mark(ch) => (),
+				Some(found) => break found,
+
+				None => {
+					self.offset = self.input.len();
+					return None;
 				}
 			}
+		};
+
+		let mut end     = input.len();
+		let mut acronym = false;
+		let mut numeric = previous.is_numeric();
+		let mut upper   = previous.is_uppercase() as usize;
+		let mut lower   = previous.is_lowercase() as usize;
+		let     title   = previous.is_uppercase();
+		let     symbol  = self.is_symbol(previous);
+
+		while let Some((i, ch)) = chars.next() {
+			if self.is_separator(ch) {
+				end = i;
+				break;
+			}
 
-			// Append any leftover lower case characters.
-			if let Some(offset) = leftover.take() {
-				result.push_str(&this[offset ..]);
+			// Combining marks belong to the character before them, `e\u{301}cole`.
+			if combining::is_mark(ch) {
+				continue;
 			}
 
-			result
+			// A run of symbols as a word of its own, `(USD)`.
+			if self.boundary.symbols == Symbols::Word && self.is_symbol(ch) != symbol {
+				end = i;
+				break;
+			}
+
+			// A letter followed by a digit or the other way around, `version2`.
+			if self.boundary.digit && ((previous.is_alphabetic() && ch.is_numeric()) || (previous.is_numeric() && ch.is_alphabetic())) {
+				end = i;
+				break;
+			}
+
+			// A lower case character or digit followed by an upper case one, `fooBar`.
+			if self.boundary.case && ch.is_uppercase() && (previous.is_lowercase() || previous.is_numeric()) {
+				end = i;
+				break;
+			}
+
+			// The last upper case character before a lower case one, `HTTPServer`.
+			if self.boundary.acronym && ch.is_uppercase() && previous.is_uppercase() && chars.peek().is_some_and(|&(_, next)| next.is_lowercase()) {
+				end     = i;
+				acronym = true;
+				break;
+			}
+
+			numeric  = numeric && ch.is_numeric();
+			upper   += ch.is_uppercase() as usize;
+			lower   += ch.is_lowercase() as usize;
+			previous = ch;
 		}
-	}
 
-	fn header(&self, _locale: Locale) -> Cow<Self> {
-		let mut chars    = self.char_indices();
-		let mut new_word = true;
+		let mut kind = kind(acronym, numeric, title, upper, lower);
 
-		while let Some((start, ch)) = chars.next() {
-			if new_word && !ch.is_uppercase() {
-				return Cow::Owned(owned(self, chars, (start, ch)));
+		// Split runs of upper case characters made of known acronyms, `HTTPURL`.
+		if kind == Kind::Upper || kind == Kind::Acronym {
+			let acronyms = &self.boundary.acronyms;
+
+			if acronyms.split(&input.as_bytes()[start .. end], self.offset + start, &mut self.acronyms) {
+				end  = self.acronyms.pop().map_or(end, |end| end - self.offset);
+				kind = Kind::Acronym;
 			}
-			else if ch == '-' {
-				new_word = true;
+			else if acronyms.contains(&input.as_bytes()[start .. end]) {
+				kind = Kind::Acronym;
 			}
-			else {
-				new_word = false;
+		}
+
+		let offset = self.offset + start;
+		self.offset += end;
+
+		Some(Word::new(offset, &input[start .. end], kind))
+	}
+}
+
+#[inline(always)]
+fn kind(acronym: bool, numeric: bool, title: bool, upper: usize, lower: usize) -> Kind {
+	if numeric {
+		Kind::Number
+	}
+	else if acronym && lower == 0 {
+		Kind::Acronym
+	}
+	else if upper == 0 {
+		Kind::Lower
+	}
+	else if lower == 0 {
+		Kind::Upper
+	}
+	else if upper == 1 && title {
+		Kind::Title
+	}
+	else {
+		Kind::Mixed
+	}
+}
+
+/// The title case version of the character, when it's different from the
+/// upper case one.
+#[inline(always)]
+fn titlecase(ch: char) -> Option<&'static str> {
+	TITLECASE.binary_search_by_key(&ch, |&(ch, _)| ch).ok().map(|index| TITLECASE[index].1)
+}
+
+/// Whether the character is already in title case, or isn't a letter at all.
+#[inline(always)]
+fn is_titlecase(ch: char) -> bool {
+	match titlecase(ch) {
+		Some(title) => title.chars().eq(Some(ch)),
+		None        => ch.is_uppercase() || !ch.is_alphabetic(),
+	}
+}
+
+/// Pushes the lower case version of the character at the given offset,
+/// handling the final sigma.
+#[inline(always)]
+fn lowercase(result: &mut String, this: &str, offset: usize, ch: char) {
+	if ch == 'Σ' {
+		result.push(greek::sigma(this, offset));
+	}
+	else {
+		result.extend(ch.to_lowercase());
+	}
+}
+
+/// Joins the words back together using the given separator, casing the first
+/// and the rest of the words following the given patterns.
+#[inline(always)]
+fn join<'a>(this: &'a str, words: Words<'_, str>, separator: Option<char>, first: Pattern, rest: Pattern, locale: Locale) -> Cow<'a, str> {
+	let mut result = Buffer::<str>::new(this);
+	write(&mut result, words, separator, first, rest, locale);
+	result.finish()
+}
+
+/// Checks if joining the words back together would give back the same string.
+#[inline(always)]
+fn check(this: &str, words: Words<'_, str>, separator: Option<char>, first: Pattern, rest: Pattern, locale: Locale) -> bool {
+	let mut result = Buffer::<str>::check(this);
+	write(&mut result, words, separator, first, rest, locale);
+	result.matches()
+}
+
+#[inline(always)]
+fn write(result: &mut Buffer<str>, words: Words<'_, str>, separator: Option<char>, first: Pattern, rest: Pattern, locale: Locale) {
+	let acronyms = words.boundary.acronyms;
+	let symbols  = words.boundary.symbols;
+
+	for (i, word) in words.enumerate() {
+		if result.is_mismatched() {
+			break;
+		}
+
+		let pattern = if i == 0 { first } else { rest };
+
+		if i != 0 {
+			if let Some(separator) = separator {
+				result.push(separator);
 			}
 		}
 
-		return Cow::Borrowed(self);
+		if symbols == Symbols::Drop {
+			push_dropping(result, word.text(), pattern, &acronyms, locale);
+		}
+		else {
+			push(result, word.text(), pattern, &acronyms, locale);
+		}
+	}
+}
 
-		#[inline(always)]
-		fn owned(this: &str, chars: CharIndices, (start, ch): (usize, char)) -> String {
-			let mut result = String::with_capacity(this.len());
-			result.push_str(&this[.. start]);
-			result.extend(ch.to_uppercase());
+/// Pushes the word into the buffer cased following the pattern, dropping any
+/// symbol within it.
+#[inline(always)]
+fn push_dropping(result: &mut Buffer<str>, word: &str, pattern: Pattern, acronyms: &Acronyms, locale: Locale) {
+	let mut pattern = pattern;
 
-			// The already properly cased starting offset, if any.
-			let mut leftover = None;
-			let mut new_word = false;
+	for part in word.split(is_symbol).filter(|part| !part.is_empty()) {
+		push(result, part, pattern, acronyms, locale);
+		pattern = pattern.rest();
+	}
+}
 
-			for (i, ch) in chars {
-				if new_word && !ch.is_uppercase() {
-					new_word = false;
+/// Whether the character is punctuation, a symbol or an emoji, combining
+/// marks belong to the character before them instead.
+#[inline(always)]
+fn is_symbol(ch: char) -> bool {
+	!ch.is_alphanumeric() && !ch.is_whitespace() && !combining::is_mark(ch)
+}
 
-					if let Some(offset) = leftover.take() {
-						result.push_str(&this[offset .. i]);
-					}
+/// Pushes the word into the buffer cased following the pattern.
+#[inline(always)]
+fn push(result: &mut Buffer<str>, word: &str, pattern: Pattern, acronyms: &Acronyms, locale: Locale) {
+	match pattern.acronym(acronyms, word.as_bytes(), word.chars().count()) {
+		Pattern::Keep =>
+			result.push_str(word),
 
-					result.extend(ch.to_uppercase());
-				}
-				else {
-					if ch == '-' {
-						new_word = true;
-					}
+		Pattern::Lower =>
+			push_lower(result, word, 0, locale),
 
-					if leftover.is_none() {
-						leftover = Some(i);
-					}
-				}
+		Pattern::Upper =>
+			push_upper(result, word, locale),
+
+		Pattern::Capital => {
+			let rest = push_capital(result, word, locale);
+			result.push_str(&word[rest ..]);
+		}
+
+		Pattern::Title =>
+			push_title(result, word, locale),
+	}
+}
+
+/// Pushes a word of a headline, following the rules.
+#[inline(always)]
+fn push_headline(result: &mut Buffer<str>, word: &str, rules: &Rules, first: bool, last: bool, shouting: bool, locale: Locale) {
+	// Any punctuation around the word is left as it is.
+	let start = word.find(char::is_alphanumeric).unwrap_or(word.len());
+	let core  = word[start ..].trim_end_matches(|ch: char| !ch.is_alphanumeric());
+
+	let intentional = if shouting {
+		headline::is_roman(core.as_bytes())
+	}
+	else {
+		core.chars().skip(1).any(char::is_uppercase)
+	};
+
+	let capitalized = core.chars().next().is_some_and(char::is_uppercase);
+	let pattern     = rules.pattern(core.as_bytes(), first, last, intentional, capitalized);
+
+	result.push_str(&word[.. start]);
+	push(result, &word[start ..], pattern, &Default::default(), locale);
+}
+
+/// Pushes a word of a sentence, capitalized if it starts the sentence.
+#[inline(always)]
+fn push_sentence(result: &mut Buffer<str>, word: &str, preserve: &[&str], first: bool, shouting: bool, locale: Locale) {
+	// Any punctuation around the word is left as it is.
+	let start = word.find(char::is_alphanumeric).unwrap_or(word.len());
+	let core  = word[start ..].trim_end_matches(|ch: char| !ch.is_alphanumeric());
+	let end   = start + core.len();
+
+	result.push_str(&word[.. start]);
+
+	if let Some(preserved) = headline::find(preserve, core.as_bytes()) {
+		result.push_str(preserved);
+	}
+	else {
+		// Acronyms and words with intentional mixed case, `MySQL`, and the
+		// English pronoun, on its own or in contractions, `I'm`.
+		let keep = (locale == Locale::None && core == "I") || (!shouting && core.chars().skip(1).any(char::is_uppercase));
+
+		let pattern = if keep {
+			Pattern::Keep
+		}
+		else if first {
+			Pattern::Title
+		}
+		else {
+			Pattern::Lower
+		};
+
+		push(result, core, pattern, &Default::default(), locale);
+	}
+
+	result.push_str(&word[end ..]);
+}
+
+/// Pushes the upper case version of the text following the locale.
+#[inline(always)]
+fn push_upper(result: &mut Buffer<str>, text: &str, locale: Locale) {
+	if locale == Locale::Greek {
+		return greek::upper(result, text);
+	}
+
+	for (i, ch) in text.char_indices() {
+		match ch {
+			'i' if locale.is_turkic() =>
+				result.push('İ'),
+
+			'ß' if locale == Locale::German =>
+				result.push('ẞ'),
+
+			'\u{307}' if locale == Locale::Lithuanian && lithuanian::is_after_soft_dotted(&text[.. i]) =>
+				(),
+
+			ch =>
+				result.extend(ch.to_uppercase()),
+		}
+	}
+}
+
+/// Pushes the title case version of the start of a word following the locale,
+/// returning the offset of the rest of the word.
+///
+/// Unlike `push_upper` Greek accents are kept, and digraphs only have their
+/// first letter in upper case, `ǅ`.
+#[inline(always)]
+fn push_capital(result: &mut Buffer<str>, word: &str, locale: Locale) -> usize {
+	// The digraph is capitalized as a whole, `IJsselmeer`.
+	if locale == Locale::Dutch && word.get(.. 2).is_some_and(|start| start.eq_ignore_ascii_case("ij")) {
+		result.push_str("IJ");
+		return 2;
+	}
+
+	// The prefix of a mutated word stays in lower case, `nAthair`.
+	if locale == Locale::Irish {
+		if let Some((prefix, start)) = irish::mutation(word) {
+			result.extend(word[.. prefix].chars().map(|ch| ch.to_ascii_lowercase()));
+			return start + push_capital(result, &word[start ..], Locale::None);
+		}
+	}
+
+	let ch = match word.chars().next() {
+		Some(ch) => ch,
+		None     => return 0,
+	};
+
+	match ch {
+		'i' if locale.is_turkic() =>
+			result.push('İ'),
+
+		'ß' if locale == Locale::German =>
+			result.push('ẞ'),
+
+		ch => match titlecase(ch) {
+			Some(title) => result.push_str(title),
+			None        => result.extend(ch.to_uppercase()),
+		}
+	}
+
+	let rest  = ch.len_utf8();
+	let marks = rest + combining::marks(&word[rest ..]);
+
+	// The combining marks on the first character are left as they are, except
+	// for a redundant dot above in Lithuanian.
+	for (i, mark) in word[rest .. marks].char_indices() {
+		if locale != Locale::Lithuanian || mark != '\u{307}' || !lithuanian::is_after_soft_dotted(&word[.. rest + i]) {
+			result.push(mark);
+		}
+	}
+
+	marks
+}
+
+/// Pushes the lower case version of the text starting at the given offset
+/// following the locale, the text before it is only used as context.
+#[inline(always)]
+fn push_lower(result: &mut Buffer<str>, text: &str, offset: usize, locale: Locale) {
+	let mut chars = text[offset ..].char_indices().peekable();
+
+	while let Some((i, ch)) = chars.next() {
+		if locale == Locale::Lithuanian {
+			if let Some(lower) = lithuanian::lower(ch, &text[offset + i + ch.len_utf8() ..]) {
+				result.push_str(lower);
+				continue;
 			}
+		}
 
-			// Append any leftover upper case characters.
-			if let Some(offset) = leftover.take() {
-				result.push_str(&this[offset ..]);
+		match ch {
+			// The combining dot above is dropped, since it's already part of `i`.
+			'I' if locale.is_turkic() && chars.peek().map(|&(_, ch)| ch) == Some('\u{307}') => {
+				chars.next();
+				result.push('i');
 			}
 
-			result
+			'I' if locale.is_turkic() =>
+				result.push('ı'),
+
+			'İ' if locale.is_turkic() =>
+				result.push('i'),
+
+			'Σ' =>
+				result.push(greek::sigma(text, offset + i)),
+
+			ch =>
+				result.extend(ch.to_lowercase()),
 		}
 	}
 }
 
+/// Pushes the text with the first character in upper case and the rest in
+/// lower case following the locale.
+#[inline(always)]
+fn push_title(result: &mut Buffer<str>, text: &str, locale: Locale) {
+	let rest = push_capital(result, text, locale);
+
+	if locale == Locale::Irish {
+		irish::lower(result, text, rest);
+	}
+	else {
+		push_lower(result, text, rest, locale);
+	}
+}
+
 #[cfg(test)]
 mod test {
 	use std::borrow::Cow;
-	use {Casing, Camel, Separator};
+	use std::cmp::Ordering;
+	use std::hash::Hasher;
+	use std::collections::HashMap;
+	use std::time::{Duration, Instant};
+	use std::collections::hash_map::DefaultHasher;
+	use {Casing, Camel, Capital, FoldMode, Case, Body, Locale, Style, Separator, Boundary, Symbols, Slug, Acronyms, Initialism, Kind};
 
 	macro_rules! assert_owned {
 		($body:expr) => (
@@ -398,21 +962,258 @@ mod test {
 		assert_owned!("FOO".lower(Default::default()));
 	}
 
+	#[test]
+	fn fold() {
+		assert_eq!("fish", "ﬁSH".fold(FoldMode::Full, Default::default()));
+		assert_eq!("ﬁsh", "ﬁSH".fold(FoldMode::Simple, Default::default()));
+		assert_eq!("strasse", "STRAẞE".fold(FoldMode::Full, Default::default()));
+		assert_eq!("straße", "STRAẞE".fold(FoldMode::Simple, Default::default()));
+		assert_eq!("σίσυφοσ", "Σίσυφος".fold(FoldMode::Full, Default::default()));
+		assert_eq!("ᏣᎳᎩ", "ꮳꮃꭹ".fold(FoldMode::Full, Default::default()));
+		assert_eq!("ᏣᎳᎩ", "ᏣᎳᎩ".fold(FoldMode::Simple, Default::default()));
+		assert_eq!("i\u{307}stanbul", "İSTANBUL".fold(FoldMode::Full, Default::default()));
+		assert_eq!("istanbul", "İSTANBUL".fold(FoldMode::Full, Locale::Turkish));
+		assert_eq!("dıyarbakır", "DIYARBAKIR".fold(FoldMode::Simple, Locale::Azerbaijani));
+		assert_eq!("İstanbul".fold(FoldMode::Full, Default::default()), "i\u{307}STANBUL".fold(FoldMode::Full, Default::default()));
+		assert_eq!("\u{1c8a}\u{19b}\u{19b}", "\u{1c89}\u{a7dc}\u{19b}".fold(FoldMode::Simple, Default::default()));
+		assert_eq!("\u{a7cf}\u{16ebb}", "\u{a7ce}\u{16ea0}".fold(FoldMode::Full, Default::default()));
+	}
+
+	#[test]
+	fn fold_allocation() {
+		assert_borrowed!("fish".fold(FoldMode::Full, Default::default()));
+		assert_borrowed!("ﬁsh".fold(FoldMode::Simple, Default::default()));
+		assert_borrowed!("straße".fold(FoldMode::Simple, Default::default()));
+		assert_borrowed!("ᏣᎳᎩ".fold(FoldMode::Full, Default::default()));
+
+		assert_owned!("ﬁsh".fold(FoldMode::Full, Default::default()));
+		assert_owned!("straße".fold(FoldMode::Full, Default::default()));
+		assert_owned!("Fish".fold(FoldMode::Simple, Default::default()));
+	}
+
+	#[test]
+	fn ignore_case() {
+		fn hash(string: &str, locale: Locale) -> u64 {
+			let mut state = DefaultHasher::new();
+			string.hash_folded(&mut state, locale);
+			state.finish()
+		}
+
+		assert!("Content-Type".eq_ignore_case("content-type", Default::default()));
+		assert!("Straße".eq_ignore_case("STRASSE", Default::default()));
+		assert!("ﬁle".eq_ignore_case("FILE", Default::default()));
+		assert!("ὈΔΥΣΣΕΎΣ".eq_ignore_case("ὀδυσσεύς", Default::default()));
+		assert!(!"Straße".eq_ignore_case("STRASE", Default::default()));
+		assert!(!"KIT".eq_ignore_case("kit", Locale::Turkish));
+		assert!("KIT".eq_ignore_case("kıt", Locale::Turkish));
+
+		assert_eq!(Ordering::Equal, "Straße".cmp_ignore_case("STRASSE", Default::default()));
+		assert_eq!(Ordering::Less, "apple".cmp_ignore_case("Banana", Default::default()));
+		assert_eq!(Ordering::Greater, "Zebra".cmp_ignore_case("apple", Default::default()));
+		assert_eq!(Ordering::Less, "STRASS".cmp_ignore_case("straße", Default::default()));
+
+		assert_eq!(hash("Straße", Default::default()), hash("STRASSE", Default::default()));
+		assert_eq!(hash(&"Ä".repeat(100), Default::default()), hash(&"ä".repeat(100), Default::default()));
+		assert_eq!(hash("KIT", Locale::Turkish), hash("kıt", Locale::Turkish));
+		assert_ne!(hash("ab", Default::default()), hash("ba", Default::default()));
+	}
+
+	#[test]
+	fn ignore_case_lower() {
+		// Characters with the same lower case in `std` must compare equal, so the
+		// folding tables can't fall behind its Unicode version; only characters
+		// with a case mapping can share their lower case with another one.
+		let mut lower = HashMap::new();
+		let mapped    = |&ch: &char| ch.to_lowercase().ne(Some(ch)) || ch.to_uppercase().ne(Some(ch));
+
+		for ch in (0 .. 0x11_0000).filter_map(char::from_u32).filter(mapped) {
+			let string = ch.to_string();
+			let first  = lower.entry(ch.to_lowercase().collect::<String>()).or_insert_with(|| string.clone());
+
+			assert!(first.eq_ignore_case(&string, Default::default()), "{:?} {:?}", first, string);
+			assert_eq!(Ordering::Equal, first.cmp_ignore_case(&string, Default::default()), "{:?} {:?}", first, string);
+		}
+	}
+
 	#[test]
 	fn capitalized() {
-		assert_eq!("Foo", "Foo".capitalized(Default::default()));
-		assert_eq!("Foo", "FoO".capitalized(Default::default()));
-		assert_eq!("Foo", "foo".capitalized(Default::default()));
-		assert_eq!("Foo", "foO".capitalized(Default::default()));
+		assert_eq!("Foo", "Foo".capitalized(Default::default(), Default::default()));
+		assert_eq!("Foo", "FoO".capitalized(Default::default(), Default::default()));
+		assert_eq!("Foo", "foo".capitalized(Default::default(), Default::default()));
+		assert_eq!("Foo", "foO".capitalized(Default::default(), Default::default()));
+	}
+
+	#[test]
+	fn capitalized_titlecase() {
+		assert_eq!("ǅungla", "ǆungla".capitalized(Default::default(), Default::default()));
+		assert_eq!("ǅungla", "ǄUNGLA".capitalized(Default::default(), Default::default()));
+		assert_eq!("ǈubav", "ǉubav".capitalized(Default::default(), Default::default()));
+		assert_eq!("ǋegoš", "ǌegoš".capitalized(Default::default(), Default::default()));
+		assert_eq!("ǲ", "ǳ".capitalized(Default::default(), Default::default()));
+		assert_eq!("Ssa", "ßa".capitalized(Default::default(), Default::default()));
+		assert_eq!("Ssa", "ßa".capitalized(Default::default(), Locale::Turkish));
+		assert_eq!("ẞa", "ßa".capitalized(Default::default(), Locale::German));
+		assert_eq!("ǅunglaǈubav", "ǆungla_ǉubav".camel(Default::default(), Camel::Upper, Default::default()));
+		assert_eq!("ǆunglaǈubav", "ǆungla_ǉubav".camel(Default::default(), Camel::Lower, Default::default()));
+		assert_eq!("ǅungla-ǈubav", "ǆungla-ǉubav".header(Default::default()));
+		assert_eq!("Ssa Ssb", "ßa ßb".to_case(Case::Title, Default::default()));
+
+		assert_borrowed!("ǅungla".capitalized(Default::default(), Default::default()));
+	}
+
+	#[test]
+	fn capitalized_letter() {
+		assert_eq!("¿Qué pasa?", "¿qué pasa?".capitalized(Capital::Letter, Default::default()));
+		assert_eq!("'Twas", "'twas".capitalized(Capital::Letter, Default::default()));
+		assert_eq!("«Bonjour»", "«bonjour»".capitalized(Capital::Letter, Default::default()));
+		assert_eq!("«Bonjour»", "«BONJOUR»".capitalized(Capital::Letter, Default::default()));
+		assert_eq!("\"İyi\"", "\"iyi\"".capitalized(Capital::Letter, Locale::Turkish));
+		assert_eq!("1st place", "1st place".capitalized(Capital::Letter, Default::default()));
+		assert_eq!("E\u{301}cole", "e\u{301}cole".capitalized(Capital::Letter, Default::default()));
+		assert_eq!("E\u{301}cole", "e\u{301}COLE".capitalized(Capital::Letter, Locale::German));
+		assert_eq!("...", "...".capitalized(Capital::Letter, Default::default()));
+
+		assert_eq!("¿qué pasa?", "¿qué pasa?".capitalized(Capital::First, Default::default()));
+		assert_eq!("'twas", "'twas".capitalized(Capital::First, Default::default()));
+		assert_eq!("Foo", "foo".capitalized(Capital::First, Default::default()));
+
+		assert_borrowed!("¿Qué pasa?".capitalized(Capital::Letter, Default::default()));
+		assert_owned!("¿qué pasa?".capitalized(Capital::Letter, Default::default()));
 	}
 
 	#[test]
 	fn capitalized_allocation() {
-		assert_borrowed!("Foo".capitalized(Default::default()));
-		assert_borrowed!("Foo-foo-æßð".capitalized(Default::default()));
+		assert_borrowed!("Foo".capitalized(Default::default(), Default::default()));
+		assert_borrowed!("Foo-foo-æßð".capitalized(Default::default(), Default::default()));
 
-		assert_owned!("fOOOOO".capitalized(Default::default()));
-		assert_owned!("REEEeE".capitalized(Default::default()));
+		assert_owned!("fOOOOO".capitalized(Default::default(), Default::default()));
+		assert_owned!("REEEeE".capitalized(Default::default(), Default::default()));
+	}
+
+	#[test]
+	fn turkic() {
+		assert_eq!("İSTANBUL", "istanbul".upper(Locale::Turkish));
+		assert_eq!("ıstanbul", "ISTANBUL".lower(Locale::Turkish));
+		assert_eq!("istanbul", "İSTANBUL".lower(Locale::Turkish));
+		assert_eq!("i", "I\u{307}".lower(Locale::Turkish));
+		assert_eq!("İstanbul", "istanbul".capitalized(Default::default(), Locale::Turkish));
+		assert_eq!("Istanbul", "ıstanbul".capitalized(Default::default(), Locale::Turkish));
+		assert_eq!("İSTANBUL", "istanbul".upper(Locale::Azerbaijani));
+		assert_eq!("ıstanbul", "ISTANBUL".lower(Locale::Azerbaijani));
+
+		assert_eq!("İzmirİli", "izmir_ili".camel(Default::default(), Camel::Upper, Locale::Turkish));
+		assert_eq!("ılık_su", "ILIK_SU".separated(Default::default(), Default::default(), Locale::Turkish));
+		assert_eq!("İç-İşler", "iç-işler".header(Locale::Turkish));
+		assert_eq!("IZMIR", "izmir".upper(Default::default()));
+	}
+
+	#[test]
+	fn turkic_allocation() {
+		assert_borrowed!("İSTANBUL".upper(Locale::Turkish));
+		assert_borrowed!("ıstanbul".lower(Locale::Turkish));
+		assert_borrowed!("İstanbul".capitalized(Default::default(), Locale::Turkish));
+
+		assert_owned!("ISTANBUL".lower(Locale::Turkish));
+	}
+
+	#[test]
+	fn greek() {
+		assert_eq!("ΑΔΙΚΟΣ, ΚΕΙΜΕΝΟ, ΙΡΙΔΑ", "άδικος, κείμενο, ίριδα".upper(Locale::Greek));
+		assert_eq!("ΑΕΡΑΣ, ΜΥΣΤΗΡΙΟ, ΩΡΑΙΟ", "Αέρας, Μυστήριο, Ωραίο".upper(Locale::Greek));
+		assert_eq!("ΜΑΪΟΥ, ΠΟΡΟΣ, ΡΥΘΜΙΣΗ", "Μαΐου, Πόρος, Ρύθμιση".upper(Locale::Greek));
+		assert_eq!("Ϋ, ΤΗΡΩ, ΜΑΪΟΣ", "ΰ, Τηρώ, Μάιος".upper(Locale::Greek));
+		assert_eq!("ΑΫΛΟΣ", "άυλος".upper(Locale::Greek));
+		assert_eq!("ΑΫΛΟΣ", "α\u{301}υλος".upper(Locale::Greek));
+		assert_eq!("ΕΝΑ Ή ΔΥΟ", "ένα ή δύο".upper(Locale::Greek));
+		assert_eq!("ΑΙ ΩΙΔΗ", "ᾳ ᾠδή".upper(Locale::Greek));
+		assert_eq!("ΆΔΙΚΟΣ", "άδικος".upper(Default::default()));
+
+		assert_eq!("Άδικος", "άδικος".capitalized(Default::default(), Locale::Greek));
+		assert_eq!("Άλφα-Βήτα", "άλφα-βήτα".header(Locale::Greek));
+	}
+
+	#[test]
+	fn final_sigma() {
+		assert_eq!("οδος", "ΟΔΟΣ".lower(Default::default()));
+		assert_eq!("οδος", "ΟΔΟΣ".lower(Locale::Greek));
+		assert_eq!("σας οδος.", "ΣΑΣ ΟΔΟΣ.".lower(Default::default()));
+		assert_eq!("σ", "Σ".lower(Default::default()));
+		assert_eq!("ας'", "ΑΣ'".lower(Default::default()));
+		assert_eq!("σοφος", "ΣΟΦΟΣ".lower(Default::default()));
+		assert_eq!("Οδος", "ΟΔΟΣ".capitalized(Default::default(), Default::default()));
+		assert_eq!("Ας", "ΑΣ".capitalized(Default::default(), Default::default()));
+		assert_eq!("Ας", "αΣ".capitalized(Default::default(), Default::default()));
+		assert_eq!("οδος_σας", "ΟΔΟΣ_ΣΑΣ".separated(Default::default(), Default::default(), Default::default()));
+	}
+
+	#[test]
+	fn lithuanian() {
+		assert_eq!("i\u{307}\u{300}", "Ì".lower(Locale::Lithuanian));
+		assert_eq!("i\u{307}\u{301}", "Í".lower(Locale::Lithuanian));
+		assert_eq!("i\u{307}\u{303}", "Ĩ".lower(Locale::Lithuanian));
+		assert_eq!("i\u{307}\u{301}", "I\u{301}".lower(Locale::Lithuanian));
+		assert_eq!("j\u{307}\u{303}", "J\u{303}".lower(Locale::Lithuanian));
+		assert_eq!("į\u{307}\u{301}", "Į\u{301}".lower(Locale::Lithuanian));
+		assert_eq!("i\u{307}\u{328}\u{301}", "I\u{328}\u{301}".lower(Locale::Lithuanian));
+		assert_eq!("ir", "IR".lower(Locale::Lithuanian));
+		assert_eq!("ì", "Ì".lower(Default::default()));
+
+		assert_eq!("I\u{300}", "i\u{307}\u{300}".upper(Locale::Lithuanian));
+		assert_eq!("J\u{303}", "j\u{307}\u{303}".upper(Locale::Lithuanian));
+		assert_eq!("A\u{307}", "a\u{307}".upper(Locale::Lithuanian));
+		assert_eq!("I\u{307}\u{300}", "i\u{307}\u{300}".upper(Default::default()));
+
+		assert_eq!("I\u{300}s", "i\u{307}\u{300}S".capitalized(Default::default(), Locale::Lithuanian));
+		assert_eq!("Ìs", "ÌS".capitalized(Default::default(), Default::default()));
+	}
+
+	#[test]
+	fn german() {
+		assert_eq!("MAẞE", "Maße".upper(Locale::German));
+		assert_eq!("MASSE", "Maße".upper(Default::default()));
+		assert_eq!("maße", "MAẞE".lower(Locale::German));
+		assert_eq!("maße", "MAẞE".lower(Default::default()));
+		assert_eq!("Maße", "MAẞE".capitalized(Default::default(), Locale::German));
+		assert_eq!("STRAẞE_1", "straße_1".to_case(Case::ScreamingSnake, Locale::German));
+
+		assert_borrowed!("STRAẞE".upper(Locale::German));
+	}
+
+	#[test]
+	fn dutch() {
+		assert_eq!("IJsselmeer", "ijsselmeer".capitalized(Default::default(), Locale::Dutch));
+		assert_eq!("IJsselmeer", "IJSSELMEER".capitalized(Default::default(), Locale::Dutch));
+		assert_eq!("Ijsselmeer", "ijsselmeer".capitalized(Default::default(), Default::default()));
+		assert_eq!("Inkt", "inkt".capitalized(Default::default(), Locale::Dutch));
+		assert_eq!("IJsselmeerIJmuiden", "ijsselmeer_ijmuiden".camel(Default::default(), Camel::Upper, Locale::Dutch));
+		assert_eq!("Het IJ", "het ij".to_case(Case::Title, Locale::Dutch));
+	}
+
+	#[test]
+	fn irish() {
+		assert_eq!("Na hÉireann", "na hÉireann".capitalized(Default::default(), Locale::Irish));
+		assert_eq!("Na héireann", "na hÉireann".capitalized(Default::default(), Default::default()));
+		assert_eq!("nAthair", "nAthair".capitalized(Default::default(), Locale::Irish));
+		assert_eq!("nAthair", "n-athair".capitalized(Default::default(), Locale::Irish));
+		assert_eq!("tUisce", "t-uisce".capitalized(Default::default(), Locale::Irish));
+		assert_eq!("hÉireann", "hÉireann".capitalized(Default::default(), Locale::Irish));
+		assert_eq!("gCorcaigh", "gcorcaigh".capitalized(Default::default(), Locale::Irish));
+		assert_eq!("bhFear", "bhfear".capitalized(Default::default(), Locale::Irish));
+		assert_eq!("bPádraig", "bPÁDRAIG".capitalized(Default::default(), Locale::Irish));
+		assert_eq!("Nathair", "nathair".capitalized(Default::default(), Locale::Irish));
+		assert_eq!("Hata", "hata".capitalized(Default::default(), Locale::Irish));
+		assert_eq!("Muintir na hÉireann", "muintir NA hÉireann".capitalized(Default::default(), Locale::Irish));
+		assert_eq!("gCorcaigh Agus bhFear", "gcorcaigh agus bhfear".to_case(Case::Title, Locale::Irish));
+		assert_eq!("I-gCorcaigh", "i-gcorcaigh".header(Locale::Irish));
+
+		// Only prefixes that can't be initials are recognized in upper case.
+		assert_eq!("gCorcaigh", "GCORCAIGH".title(Locale::Irish));
+		assert_eq!("I gCorcaigh", "I GCORCAIGH".title(Locale::Irish));
+		assert_eq!("Na gCorcaigh", "NA GCORCAIGH".capitalized(Default::default(), Locale::Irish));
+		assert_eq!("bhFear", "BHFEAR".capitalized(Default::default(), Locale::Irish));
+		assert_eq!("nAthair", "N-ATHAIR".capitalized(Default::default(), Locale::Irish));
+		assert_eq!("Na Héireann", "NA HÉIREANN".title(Locale::Irish));
+		assert_eq!("Hata", "HATA".title(Locale::Irish));
 	}
 
 	#[test]
@@ -428,27 +1229,142 @@ mod test {
 		assert_eq!("foo", "foo".camel(Default::default(), Camel::Lower, Default::default()));
 	}
 
+	#[test]
+	fn camel_normalized() {
+		assert_eq!("FOOBAR", "FOO_BAR".camel(Default::default(), Camel::Upper, Default::default()));
+		assert_eq!("FooBar", "FOO_BAR".camel(Default::default(), Camel::UpperNormalized, Default::default()));
+		assert_eq!("FooBar", "foo_BAR".camel(Default::default(), Camel::UpperNormalized, Default::default()));
+		assert_eq!("fooBAR", "foo_BAR".camel(Default::default(), Camel::Lower, Default::default()));
+		assert_eq!("fooBar", "foo_BAR".camel(Default::default(), Camel::LowerNormalized, Default::default()));
+		assert_eq!("fooBar", "FOO_BAR".camel(Default::default(), Camel::LowerNormalized, Default::default()));
+		assert_eq!("maxPathLen", "MAX_PATH_LEN".camel(Default::default(), Camel::LowerNormalized, Default::default()));
+		assert_eq!("ΟδόςΣτάσης", "ΟΔΌΣ_ΣΤΆΣΗΣ".camel(Default::default(), Camel::UpperNormalized, Default::default()));
+	}
+
+	#[test]
+	fn camel_acronyms() {
+		let title = Boundary { acronyms: Acronyms::new(Acronyms::COMMON, Initialism::Title), .. Default::default() };
+		let upper = Boundary { acronyms: Acronyms::new(Acronyms::COMMON, Initialism::Upper), .. Default::default() };
+		let short = Boundary { acronyms: Acronyms::new(Acronyms::COMMON, Initialism::Short), .. Default::default() };
+
+		assert_eq!("HttpUrl", "http_url".camel(title, Camel::Upper, Default::default()));
+		assert_eq!("HttpUrl", "HTTPURL".camel(title, Camel::Upper, Default::default()));
+		assert_eq!("HTTPURL", "http_url".camel(upper, Camel::Upper, Default::default()));
+		assert_eq!("httpURL", "http_url".camel(upper, Camel::Lower, Default::default()));
+		assert_eq!("UserID", "user_id".camel(upper, Camel::Upper, Default::default()));
+		assert_eq!("IOStream", "io_stream".camel(short, Camel::Upper, Default::default()));
+		assert_eq!("HttpUrl", "http_url".camel(short, Camel::Upper, Default::default()));
+	}
+
+	#[test]
+	fn camel_acronyms_long() {
+		// Each run of upper case characters is split once, scanning it again
+		// for every acronym takes seconds.
+		let upper = Boundary { acronyms: Acronyms::new(Acronyms::COMMON, Initialism::Upper), .. Default::default() };
+		let input = "ID".repeat(10_000);
+		let start = Instant::now();
+
+		assert_eq!(input, "ID_".repeat(10_000).camel(upper, Camel::Upper, Default::default()));
+		assert_eq!(input, input.camel(upper, Camel::Upper, Default::default()));
+		assert_eq!(10_000, input.words(upper).count());
+		assert!(start.elapsed() < Duration::from_secs(2), "{:?}", start.elapsed());
+	}
+
 	#[test]
 	fn camel_allocation() {
 		assert_borrowed!("FooBar".camel(Default::default(), Camel::Upper, Default::default()));
 		assert_borrowed!("fooBar".camel(Default::default(), Camel::Lower, Default::default()));
+		assert_borrowed!("FooBar".camel(Default::default(), Camel::UpperNormalized, Default::default()));
+		assert_borrowed!("fooBar".camel(Default::default(), Camel::LowerNormalized, Default::default()));
+
+		assert_owned!("FOO_BAR".camel(Default::default(), Camel::UpperNormalized, Default::default()));
+		assert_owned!("FOOBar".camel(Default::default(), Camel::LowerNormalized, Default::default()));
 	}
 
 	#[test]
 	fn separated() {
-		assert_eq!("foo_bar", "foo_bar".separated(Separator('_'), Default::default()));
-		assert_eq!("foo-bar-baz", "foo_bar_baz".separated(Separator('-'), Default::default()));
+		assert_eq!("foo_bar", "foo_bar".separated(Default::default(), Separator('_'), Default::default()));
+		assert_eq!("foo-bar-baz", "foo_bar_baz".separated(Default::default(), Separator('-'), Default::default()));
+		assert_eq!("foo_bar", "FooBar".separated(Default::default(), Separator('_'), Default::default()));
+		assert_eq!("xml_http_request", "XMLHttpRequest".separated(Default::default(), Separator('_'), Default::default()));
+		assert_eq!("version2_update", "version2Update".separated(Default::default(), Separator('_'), Default::default()));
+		assert_eq!("version_2_update", "version2Update".separated(Boundary { digit: true, .. Default::default() }, Separator('_'), Default::default()));
+		assert_eq!("utf8_decoder", "utf8Decoder".separated(Default::default(), Separator('_'), Default::default()));
+		assert_eq!("v2_api", "v2Api".separated(Default::default(), Separator('_'), Default::default()));
+		assert_eq!("http_server", "HTTPServer".separated(Default::default(), Separator('_'), Default::default()));
+		assert_eq!("http_url", "HTTPURL".separated(Boundary { acronyms: Acronyms::new(Acronyms::COMMON, Initialism::Title), .. Default::default() }, Separator('_'), Default::default()));
 	}
 
 	#[test]
 	fn separated_allocation() {
-		assert_borrowed!("foo_bar".separated(Separator('_'), Default::default()));
-		assert_borrowed!("foo-bar".separated(Separator('-'), Default::default()));
-		assert_borrowed!("foo@bar".separated(Separator('@'), Default::default()));
+		assert_borrowed!("foo_bar".separated(Default::default(), Separator('_'), Default::default()));
+		assert_borrowed!("foo-bar".separated(Default::default(), Separator('-'), Default::default()));
+		assert_borrowed!("foo@bar".separated(Default::default(), Separator('@'), Default::default()));
+
+		assert_owned!("foo_bar".separated(Default::default(), Separator('@'), Default::default()));
+		assert_owned!("foo-bar".separated(Default::default(), Separator('_'), Default::default()));
+		assert_owned!("foo@bar".separated(Default::default(), Separator('-'), Default::default()));
+	}
+
+	#[test]
+	fn symbols() {
+		let boundary = |symbols| Boundary { symbols, .. Default::default() };
+
+		assert_eq!("total_price_usd", "Total Price (USD)".separated(boundary(Symbols::Separate), Separator('_'), Default::default()));
+		assert_eq!("foo_bar", "  foo -- bar!  ".separated(boundary(Symbols::Separate), Separator('_'), Default::default()));
+
+		assert_eq!("total_price_usd", "Total Price (USD)".separated(boundary(Symbols::Drop), Separator('_'), Default::default()));
+		assert_eq!("dont_stop", "Don't stop!".separated(boundary(Symbols::Drop), Separator('_'), Default::default()));
+		assert_eq!("i_rust", "I ❤️ Rust".separated(boundary(Symbols::Drop), Separator('_'), Default::default()));
+		assert_eq!("DontStop", "don't  stop".camel(boundary(Symbols::Drop), Camel::UpperNormalized, Default::default()));
+		assert_eq!("totalPriceUsd", "Total Price (USD)".camel(boundary(Symbols::Drop), Camel::LowerNormalized, Default::default()));
+
+		assert_eq!("a.b c_d", "a.b c-d".separated(boundary(Symbols::Attach), Separator('_'), Default::default()));
+
+		assert_eq!("total_price_(_usd_)", "Total Price (USD)".separated(boundary(Symbols::Word), Separator('_'), Default::default()));
+		assert_eq!("i_❤️_rust", "I ❤️ Rust".separated(boundary(Symbols::Word), Separator('_'), Default::default()));
+		assert_eq!("a_._b", "a.b".separated(boundary(Symbols::Word), Separator('_'), Default::default()));
+
+		assert_eq!("e\u{301}cole_normale", "E\u{301}cole Normale!".separated(boundary(Symbols::Drop), Separator('_'), Default::default()));
+		assert_eq!("l'e\u{301}cole", "l'e\u{301}cole".separated(boundary(Symbols::Attach), Separator('_'), Default::default()));
+		assert_eq!("le\u{301}cole", "l'e\u{301}cole".separated(boundary(Symbols::Drop), Separator('_'), Default::default()));
+		assert_eq!("e\u{301}cole_(_x_)", "e\u{301}cole (x)".separated(boundary(Symbols::Word), Separator('_'), Default::default()));
+	}
+
+	#[test]
+	fn symbols_allocation() {
+		let boundary = |symbols| Boundary { symbols, .. Default::default() };
+
+		assert_borrowed!("total_price".separated(boundary(Symbols::Drop), Separator('_'), Default::default()));
+		assert_borrowed!("a_._b".separated(boundary(Symbols::Word), Separator('_'), Default::default()));
+		assert_borrowed!("dont_stop!".separated(boundary(Symbols::Drop), Separator('_'), Default::default()));
+
+		assert_owned!("don't_stop".separated(boundary(Symbols::Drop), Separator('_'), Default::default()));
+		assert_owned!("a._b".separated(boundary(Symbols::Word), Separator('_'), Default::default()));
+	}
+
+	#[test]
+	fn title() {
+		assert_eq!("Large Red Mug", "LARGE RED MUG".title(Default::default()));
+		assert_eq!("Large Red Mug", "large red mug".title(Default::default()));
+		assert_eq!("Hello,  World!\tFoo-Bar_Baz", "hello,  WORLD!\tfoo-bar_baz".title(Default::default()));
+		assert_eq!("Don't Stop Believin'", "don't stop believin'".title(Default::default()));
+		assert_eq!("3rd Of 4", "3RD OF 4".title(Default::default()));
+		assert_eq!(" «Élan» Vital ", " «élan» vital ".title(Default::default()));
+		assert_eq!("E\u{301}cole Normale", "e\u{301}cole normale".title(Default::default()));
+		assert_eq!("ǅungla Ssa", "ǆungla ßa".title(Default::default()));
+		assert_eq!("İyi Akşamlar", "iyi akşamlar".title(Locale::Turkish));
+		assert_eq!("Οδος Σας", "ΟΔΟΣ ΣΑΣ".title(Default::default()));
+		assert_eq!("", "".title(Default::default()));
+	}
 
-		assert_owned!("foo_bar".separated(Separator('@'), Default::default()));
-		assert_owned!("foo-bar".separated(Separator('_'), Default::default()));
-		assert_owned!("foo@bar".separated(Separator('-'), Default::default()));
+	#[test]
+	fn title_allocation() {
+		assert_borrowed!("Large Red Mug".title(Default::default()));
+		assert_borrowed!("Hello,  World!\tFoo-Bar".title(Default::default()));
+		assert_borrowed!("--".title(Default::default()));
+
+		assert_owned!("Large red Mug".title(Default::default()));
 	}
 
 	#[test]
@@ -473,6 +1389,292 @@ mod test {
 		assert_owned!("foo-bar".header(Default::default()));
 		assert_owned!("foo-bar-baz".header(Default::default()));
 	}
+
+	#[test]
+	fn train() {
+		let upper = Boundary { acronyms: Acronyms::new(&["MIME"], Initialism::Upper), .. Default::default() };
+
+		assert_eq!("Created-At", "createdAt".train(Default::default(), Separator('-'), Body::Keep, Default::default()));
+		assert_eq!("Created At", "createdAt".train(Default::default(), Separator(' '), Body::Keep, Default::default()));
+		assert_eq!("Created-At", "created_at".train(Default::default(), Separator('-'), Body::Keep, Default::default()));
+		assert_eq!("MIME-Type", "MIME_type".train(Default::default(), Separator('-'), Body::Keep, Default::default()));
+		assert_eq!("Mime-Type", "MIME_TYPE".train(Default::default(), Separator('-'), Body::Lower, Default::default()));
+		assert_eq!("MIME-Type", "mime_TYPE".train(upper, Separator('-'), Body::Lower, Default::default()));
+		assert_eq!("Élan Vital", "élan_VITAL".train(Default::default(), Separator(' '), Body::Lower, Default::default()));
+	}
+
+	#[test]
+	fn train_allocation() {
+		assert_borrowed!("Created-At".train(Default::default(), Separator('-'), Body::Keep, Default::default()));
+		assert_borrowed!("Created At".train(Boundary::only(&[' ']), Separator(' '), Body::Lower, Default::default()));
+
+		assert_owned!("createdAt".train(Default::default(), Separator('-'), Body::Keep, Default::default()));
+		assert_owned!("Created_At".train(Default::default(), Separator('-'), Body::Keep, Default::default()));
+	}
+
+	#[test]
+	fn headline() {
+		assert_eq!("The Lord of the Rings", "the lord of the rings".headline(Style::Chicago, Default::default()));
+		assert_eq!("The Lord of the Rings", "THE LORD OF THE RINGS".headline(Style::Chicago, Default::default()));
+		assert_eq!("A Tale of Two Cities", "a tale of two cities".headline(Style::Apa, Default::default()));
+		assert_eq!("What It Is Made Of", "what it is made of".headline(Style::Chicago, Default::default()));
+		assert_eq!("Star Wars: The Empire Strikes Back", "star wars: the empire strikes back".headline(Style::Mla, Default::default()));
+		assert_eq!("Self-Driving Cars in the City", "self-driving cars in the city".headline(Style::Ap, Default::default()));
+		assert_eq!("Out-of-Bounds Rules", "out-of-bounds rules".headline(Style::Chicago, Default::default()));
+		assert_eq!("Why the iPhone Beat eBay at NASA", "why the iPhone beat eBay at NASA".headline(Style::Ap, Default::default()));
+		assert_eq!("Rocky VIII and Henry VIII", "ROCKY VIII AND HENRY VIII".headline(Style::Chicago, Default::default()));
+		assert_eq!("\"Into the Wild\"  (Part Two)", "\"into the wild\"  (part two)".headline(Style::Chicago, Default::default()));
+
+		// Long prepositions are minor words only for Chicago and MLA.
+		assert_eq!("Walking through the Woods", "walking through the woods".headline(Style::Chicago, Default::default()));
+		assert_eq!("Walking Through the Woods", "walking through the woods".headline(Style::Apa, Default::default()));
+
+		assert_eq!("İstanbul ve İzmir", "istanbul ve İzmir".headline(Style::Chicago, Locale::Turkish));
+	}
+
+	#[test]
+	fn headline_locale() {
+		assert_eq!("Le petit prince à Paris", "le petit prince à Paris".headline(Default::default(), Locale::French));
+		assert_eq!("Le petit prince à paris", "Le Petit Prince À Paris".headline(Default::default(), Locale::French));
+		assert_eq!("Le petit prince à paris", "LE PETIT PRINCE À PARIS".headline(Default::default(), Locale::French));
+		assert_eq!("La casa de papel: Parte uno", "la casa de papel: parte uno".headline(Default::default(), Locale::Spanish));
+		assert_eq!("Cien años de soledad en la UNAM", "cien años de soledad en la UNAM".headline(Default::default(), Locale::Spanish));
+		assert_eq!("Die Verwandlung und das Schloss", "die Verwandlung und das Schloss".headline(Default::default(), Locale::German));
+		assert_eq!("Il Nome della Rosa", "il nome della rosa".headline(Default::default(), Locale::Italian));
+		assert_eq!("O Tempo e o Vento", "o tempo e o vento".headline(Default::default(), Locale::Portuguese));
+		assert_eq!("Memórias Póstumas de Brás Cubas", "MEMÓRIAS PÓSTUMAS DE BRÁS CUBAS".headline(Default::default(), Locale::Portuguese));
+		assert_eq!("Ο άρχοντας των δαχτυλιδιών", "ο άρχοντας των δαχτυλιδιών".headline(Default::default(), Locale::Greek));
+		assert_eq!("Ο άρχοντας των δαχτυλιδιών", "Ο ΆΡΧΟΝΤΑΣ ΤΩΝ ΔΑΧΤΥΛΙΔΙΏΝ".headline(Default::default(), Locale::Greek));
+		assert_eq!("Kürk mantolu Madonna", "kürk mantolu Madonna".headline(Default::default(), Locale::Turkish));
+		assert_eq!("Bakı küçələri", "bakı küçələri".headline(Default::default(), Locale::Azerbaijani));
+		assert_eq!("Žiedų valdovas", "žiedų valdovas".headline(Default::default(), Locale::Lithuanian));
+		assert_eq!("Muintir na hÉireann", "muintir na hÉireann".headline(Default::default(), Locale::Irish));
+	}
+
+	#[test]
+	fn headline_allocation() {
+		assert_borrowed!("The Lord of the Rings".headline(Style::Chicago, Default::default()));
+		assert_borrowed!("Why the iPhone Beat eBay".headline(Style::Ap, Default::default()));
+
+		assert_owned!("the Lord of the Rings".headline(Style::Chicago, Default::default()));
+	}
+
+	#[test]
+	fn sentence() {
+		let brands = &["GitHub", "PostgreSQL", "London"];
+
+		assert_eq!("Getting started with GitHub", "Getting Started With GitHub".sentence(brands, Default::default()));
+		assert_eq!("Moving from MySQL to PostgreSQL in London", "Moving From MySQL To Postgresql In LONDON".sentence(brands, Default::default()));
+		assert_eq!("Use the API. Then check the logs! Done? Yes.", "use the API. then Check the Logs! done? yes.".sentence(&[], Default::default()));
+		assert_eq!("Ask Dr. who, e.g. the doctor. Then leave.", "ask Dr. Who, e.g. The Doctor. then leave.".sentence(&["Dr"], Default::default()));
+		assert_eq!("The U.S. team won. «Bravo», he said.", "the U.S. Team won. «bravo», He said.".sentence(&[], Default::default()));
+		assert_eq!("He said \"stop.\" Then he left.", "he said \"stop.\" then he left.".sentence(&[], Default::default()));
+		assert_eq!("He said no. Try again.", "He said no. Try again.".sentence(&[], Default::default()));
+		assert_eq!("How I built this", "How I Built This".sentence(&[], Default::default()));
+		assert_eq!("I'm sure I've seen it", "I'M SURE I'VE SEEN IT".sentence(&[], Default::default()));
+		assert_eq!("Leggo i promessi sposi", "Leggo I Promessi Sposi".sentence(&[], Locale::Italian));
+		assert_eq!("GitHub's self-hosted runners", "GITHUB'S SELF-HOSTED RUNNERS".sentence(brands, Default::default()));
+		assert_eq!("İstanbul ve Ankara", "istanbul ve ankara".sentence(&["Ankara"], Locale::Turkish));
+		assert_eq!("¿Qué pasa? ¡Nada!", "¿QUÉ PASA? ¡NADA!".sentence(&[], Default::default()));
+	}
+
+	#[test]
+	fn sentence_allocation() {
+		assert_borrowed!("Getting started with GitHub and the API.".sentence(&["GitHub"], Default::default()));
+		assert_owned!("Getting Started".sentence(&[], Default::default()));
+	}
+
+	#[test]
+	fn slug() {
+		let stopwords = &["a", "à", "la", "the"];
+
+		assert_eq!("creme-brulee-a-la-mode", "Crème Brûlée à la Mode".slug(Default::default()));
+		assert_eq!("strasse", "Straße".slug(Default::default()));
+		assert_eq!("ellada", "Ελλάδα".slug(Default::default()));
+		assert_eq!("o-koukos-stin-athina", "Ο κούκος στην Αθήνα".slug(Default::default()));
+		assert_eq!("moskva-kiiv", "Москва — Київ".slug(Default::default()));
+		assert_eq!("dont-stop-me-now", "Don't stop me now!".slug(Default::default()));
+		assert_eq!("total-price-usd", "  Total Price (USD)  ".slug(Default::default()));
+		assert_eq!("tokyo-2020", "東京 Tokyo 2020".slug(Default::default()));
+		assert_eq!("istanbul", "İSTANBUL".slug(Default::default()));
+		assert_eq!("creme_brulee_mode", "Crème Brûlée à la Mode".slug(Slug { separator: '_', stopwords, .. Default::default() }));
+		assert_eq!("creme-brulee", "Crème Brûlée à la Mode".slug(Slug { length: Some(13), .. Default::default() }));
+		assert_eq!("creme", "Crème Brûlée à la Mode".slug(Slug { length: Some(11), .. Default::default() }));
+		assert_eq!("cre", "Crème Brûlée à la Mode".slug(Slug { length: Some(3), .. Default::default() }));
+		assert_eq!("creme", "Crème Brûlée".slug(Slug { separator: '·', length: Some(12), .. Default::default() }));
+		assert_eq!("creme·brulee", "Crème Brûlée".slug(Slug { separator: '·', length: Some(13), .. Default::default() }));
+		assert_eq!("", "The".slug(Slug { stopwords, .. Default::default() }));
+	}
+
+	#[test]
+	fn slug_allocation() {
+		assert_borrowed!("creme-brulee-a-la-mode".slug(Default::default()));
+		assert_borrowed!("2024-release-notes".slug(Default::default()));
+		assert_borrowed!("creme-brulee-a-la-mode".slug(Slug { length: Some(12), .. Default::default() }));
+
+		assert_owned!("Crème Brûlée".slug(Default::default()));
+		assert_owned!("creme--brulee".slug(Default::default()));
+		assert_owned!("creme_brulee".slug(Default::default()));
+	}
+
+	#[test]
+	fn to_case() {
+		assert_eq!("foo_bar_baz", "fooBarBaz".to_case(Case::Snake, Default::default()));
+		assert_eq!("foo-bar-baz", "FooBarBaz".to_case(Case::Kebab, Default::default()));
+		assert_eq!("FOO_BAR_BAZ", "foo-bar-baz".to_case(Case::ScreamingSnake, Default::default()));
+		assert_eq!("fooBarBaz", "FOO_BAR_BAZ".to_case(Case::Camel, Default::default()));
+		assert_eq!("xmlHttpRequest", "XMLHttpRequest".to_case(Case::Camel, Default::default()));
+		assert_eq!("FooBarBaz", "foo bar baz".to_case(Case::Pascal, Default::default()));
+		assert_eq!("Foo-Bar-Baz", "foo_BAR_baz".to_case(Case::Train, Default::default()));
+		assert_eq!("MIME-Type", "MIME_type".to_case(Case::Header, Default::default()));
+		assert_eq!("Foo Bar Baz", "foo_bar_baz".to_case(Case::Title, Default::default()));
+		assert_eq!("Foo bar baz", "fooBarBaz".to_case(Case::Sentence, Default::default()));
+		assert_eq!("foobarbaz", "foo_bar_baz".to_case(Case::Flat, Default::default()));
+		assert_eq!("FOOBARBAZ", "foo_bar_baz".to_case(Case::UpperFlat, Default::default()));
+		assert_eq!("foo.bar.baz", "fooBarBaz".to_case(Case::Dot, Default::default()));
+		assert_eq!("foo/bar/baz", "fooBarBaz".to_case(Case::Path, Default::default()));
+		assert_eq!("FOO-BAR-BAZ", "fooBarBaz".to_case(Case::Cobol, Default::default()));
+		assert_eq!("Foo_Bar_Baz", "FOO_BAR_BAZ".to_case(Case::Ada, Default::default()));
+		assert_eq!("ærø_ðð", "ÆrøÐÐ".to_case(Case::Snake, Default::default()));
+	}
+
+	#[test]
+	fn combining_marks() {
+		assert_eq!("e\u{301}cole_normale", "E\u{301}coleNormale".to_case(Case::Snake, Default::default()));
+		assert_eq!("e\u{301}cole_normale", "e\u{301}cole_normale".to_case(Case::Snake, Default::default()));
+		assert_eq!("E\u{301}coleNormale", "e\u{301}cole_normale".camel(Default::default(), Camel::Upper, Default::default()));
+		assert_eq!("J\u{30c}_X", "ǰ_x".to_case(Case::ScreamingSnake, Default::default()));
+		assert!("e\u{301}cole".is_snake());
+		assert!("J\u{30c}X".is_screaming_snake());
+
+		for input in &["ǰx_y", "e\u{301}cole_normale", "ΐ_ﬁle", "a\u{308}\u{301}x_bc"] {
+			for &case in Case::ALL {
+				assert!(input.to_case(case, Default::default()).is_case(case), "{} to {}", input, case);
+			}
+		}
+	}
+
+	#[test]
+	fn to_case_allocation() {
+		assert_borrowed!("foo_bar".to_case(Case::Snake, Default::default()));
+		assert_borrowed!("FOO_BAR".to_case(Case::ScreamingSnake, Default::default()));
+		assert_borrowed!("fooBar".to_case(Case::Camel, Default::default()));
+		assert_borrowed!("Foo Bar".to_case(Case::Title, Default::default()));
+		assert_borrowed!("FOO-BAR".to_case(Case::Cobol, Default::default()));
+		assert_borrowed!("Foo_Bar".to_case(Case::Ada, Default::default()));
+		assert_borrowed!("foo.bar".to_case(Case::Dot, Default::default()));
+		assert_borrowed!("foo/bar".to_case(Case::Path, Default::default()));
+		assert_borrowed!("foobar".to_case(Case::Flat, Default::default()));
+		assert_borrowed!("FOOBAR".to_case(Case::UpperFlat, Default::default()));
+
+		assert_owned!("fooBar".to_case(Case::Snake, Default::default()));
+		assert_owned!("foo_bar".to_case(Case::Kebab, Default::default()));
+		assert_owned!("FOO_BAR".to_case(Case::Pascal, Default::default()));
+		assert_owned!("FOO_BAR".to_case(Case::Cobol, Default::default()));
+		assert_owned!("Foo_bar".to_case(Case::Ada, Default::default()));
+	}
+
+	#[test]
+	fn is_case() {
+		assert!("foo_bar".is_snake());
+		assert!("foo-bar".is_kebab());
+		assert!("FOO_BAR".is_screaming_snake());
+		assert!("fooBar".is_camel());
+		assert!("FooBar".is_pascal());
+		assert!("MIME-Type".is_case(Case::Header));
+		assert!("Foo bar".is_case(Case::Sentence));
+		assert!("FOO-BAR".is_case(Case::Cobol));
+		assert!("Foo_Bar".is_case(Case::Ada));
+
+		assert!(!"foo__bar".is_snake());
+		assert!(!"_foo_bar".is_snake());
+		assert!(!"foo_bar_".is_snake());
+		assert!(!"foo_Bar".is_snake());
+		assert!(!"FOO_BAr".is_screaming_snake());
+		assert!(!"FooBar".is_camel());
+		assert!(!"fooBar".is_pascal());
+		assert!(!"FOOBar".is_pascal());
+	}
+
+	#[test]
+	fn detect_case() {
+		let cases = "foo".detect_case();
+		assert!(cases.contains(Case::Snake));
+		assert!(cases.contains(Case::Camel));
+		assert!(cases.contains(Case::Flat));
+		assert!(!cases.contains(Case::Pascal));
+
+		assert_eq!(vec![Case::Pascal], "FooBar".detect_case().iter().collect::<Vec<_>>());
+		assert_eq!(vec![Case::Snake], "foo_bar".detect_case().iter().collect::<Vec<_>>());
+		assert!("foo_Bar-baz".detect_case().is_empty());
+	}
+
+	#[test]
+	fn is_case_empty() {
+		assert!(!"".is_snake());
+		assert!(!"".is_camel());
+		assert!(!"__".is_snake());
+		assert!("".detect_case().is_empty());
+		assert!("--".detect_case().is_empty());
+	}
+
+	#[test]
+	fn words() {
+		fn words(string: &str) -> Vec<(&str, Kind)> {
+			string.words(Default::default()).map(|w| (w.text(), w.kind())).collect()
+		}
+
+		assert_eq!(vec![("foo", Kind::Lower), ("Bar", Kind::Title)], words("fooBar"));
+		assert_eq!(vec![("FOO", Kind::Upper), ("BAR", Kind::Upper)], words("FOO_BAR"));
+		assert_eq!(vec![("foo", Kind::Lower), ("bar", Kind::Lower)], words("foo-bar"));
+		assert_eq!(vec![("XML", Kind::Acronym), ("Http", Kind::Title), ("Request", Kind::Title)], words("XMLHttpRequest"));
+		assert_eq!(vec![("utf8", Kind::Lower), ("Decoder", Kind::Title)], words("utf8Decoder"));
+		assert_eq!(vec![("version", Kind::Lower), ("2", Kind::Number)], words("version 2"));
+		assert_eq!(vec![("Ærø", Kind::Title), ("ÐÐ", Kind::Upper)], words("ÆrøÐÐ"));
+		assert_eq!(vec![("foo", Kind::Lower)], words("__foo--"));
+		assert_eq!(vec![("foo\u{1e4ec}bar", Kind::Lower)], words("foo\u{1e4ec}bar"));
+		assert_eq!(vec![("foo\u{1e5ee}bar", Kind::Lower)], words("foo\u{1e5ee}bar"));
+		assert!(words("_-_").is_empty());
+	}
+
+	#[test]
+	fn words_copy() {
+		let mut words = "fooBar_baz".words(Default::default());
+		let word      = words.next().unwrap();
+		let copy      = word;
+
+		assert_eq!(word, copy);
+		assert_eq!(words.clone().count(), 2);
+		assert_eq!(words.count(), 2);
+	}
+
+	#[test]
+	fn words_boundary() {
+		fn words<'a>(string: &'a str, boundary: Boundary<'a, char>) -> Vec<&'a str> {
+			string.words(boundary).map(|w| w.text()).collect()
+		}
+
+		assert_eq!(vec!["version", "2", "Update"], words("version2Update", Boundary { digit: true, .. Default::default() }));
+		assert_eq!(vec!["v", "2", "api"], words("v2api", Boundary { digit: true, .. Default::default() }));
+		assert_eq!(vec!["fooBar"], words("fooBar", Boundary { case: false, .. Default::default() }));
+		assert_eq!(vec!["HTTPServer"], words("HTTPServer", Boundary { acronym: false, .. Default::default() }));
+		assert_eq!(vec!["foo bar", "baz"], words("foo bar_baz", Boundary::from(Separator(&['_'][..]))));
+		assert_eq!(vec!["fooBar", "baz"], words("fooBar.baz", Boundary::only(&['.'])));
+	}
+
+	#[test]
+	fn words_acronyms() {
+		let boundary = Boundary { acronyms: Acronyms::new(&["HTTP", "HTTPS", "URL"], Initialism::Title), .. Default::default() };
+		let words    = "HTTPSURLParser".words(boundary).map(|w| (w.text(), w.kind())).collect::<Vec<_>>();
+
+		assert_eq!(vec![("HTTPS", Kind::Acronym), ("URL", Kind::Acronym), ("Parser", Kind::Title)], words);
+	}
+
+	#[test]
+	fn words_offset() {
+		let offsets = "foo_barBaz".words(Default::default()).map(|w| w.offset()).collect::<Vec<_>>();
+		assert_eq!(vec![0, 4, 7], offsets);
+	}
 }
 
 #[cfg(test)]
@@ -542,12 +1744,12 @@ mod bench {
 
 	#[bench]
 	fn capitalized_owned(b: &mut Bencher) {
-		b.iter(|| "aAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA".capitalized(Default::default()));
+		b.iter(|| "aAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA".capitalized(Default::default(), Default::default()));
 	}
 
 	#[bench]
 	fn capitalized_borrowed(b: &mut Bencher) {
-		b.iter(|| "Aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".capitalized(Default::default()));
+		b.iter(|| "Aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".capitalized(Default::default(), Default::default()));
 	}
 
 	#[bench]
@@ -560,6 +1762,16 @@ mod bench {
 		b.iter(|| "FooBarBaz".header(Default::default()))
 	}
 
+	#[bench]
+	fn title_owned(b: &mut Bencher) {
+		b.iter(|| "Large red mug".title(Default::default()))
+	}
+
+	#[bench]
+	fn title_borrowed(b: &mut Bencher) {
+		b.iter(|| "Large Red Mug".title(Default::default()))
+	}
+
 	#[bench]
 	fn header_owned(b: &mut Bencher) {
 		b.iter(|| "Foo-Bar-baz".header(Default::default()))
@@ -569,4 +1781,24 @@ mod bench {
 	fn header_borrowed(b: &mut Bencher) {
 		b.iter(|| "Foo-Bar-Baz".header(Default::default()))
 	}
+
+	#[bench]
+	fn words(b: &mut Bencher) {
+		b.iter(|| "XMLHttpRequest_foo-bar".words(Default::default()).count())
+	}
+
+	#[bench]
+	fn eq_ignore_case_ascii(b: &mut Bencher) {
+		b.iter(|| "Content-Type".eq_ignore_case("content-type", Default::default()));
+	}
+
+	#[bench]
+	fn eq_ignore_case_unicode(b: &mut Bencher) {
+		b.iter(|| "Straße Ὀδυσσεύς".eq_ignore_case("STRASSE ὈΔΥΣΣΕΎΣ", Default::default()));
+	}
+
+	#[bench]
+	fn eq_ignore_case_lower(b: &mut Bencher) {
+		b.iter(|| "Straße Ὀδυσσεύς".lower(Default::default()) == "STRASSE ὈΔΥΣΣΕΎΣ".lower(Default::default()));
+	}
 }
@@ -0,0 +1,405 @@
+use std::mem;
+use std::ops::{Deref, Index, Range};
+use {Casing, Separator};
+
+/// Rules deciding where a word ends and the next one starts.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Boundary<'a, T: Copy + Eq + 'a> {
+	/// Characters that separate words, they are not part of any word.
+	pub separators: &'a [T],
+
+	/// What happens to any other character that is neither a letter nor a
+	/// digit, `foo.bar`.
+	pub symbols: Symbols,
+
+	/// Whether an upper case character following a lower case character or a
+	/// digit starts a new word, `fooBar` and `v2Api`.
+	pub case: bool,
+
+	/// Whether the last upper case character in a run followed by a lower case
+	/// character starts a new word, `HTTPServer`.
+	pub acronym: bool,
+
+	/// Whether a change from letters to digits, or from digits to letters,
+	/// starts a new word, `version2` and `2nd`.
+	pub digit: bool,
+
+	/// Known acronyms, used to split runs of upper case characters and to
+	/// decide how they're cased when joining words.
+	pub acronyms: Acronyms<'a>,
+}
+
+impl Default for Boundary<'_, char> {
+	fn default() -> Self {
+		Boundary {
+			symbols: Symbols::Separate,
+			.. Separator::<&[char]>::default().into()
+		}
+	}
+}
+
+impl Default for Boundary<'_, u8> {
+	fn default() -> Self {
+		Boundary {
+			symbols: Symbols::Separate,
+			.. Separator::<&[u8]>::default().into()
+		}
+	}
+}
+
+/// Splits on the given separators and on case changes.
+impl<'a, T: Copy + Eq + 'a> From<Separator<&'a [T]>> for Boundary<'a, T> {
+	fn from(value: Separator<&'a [T]>) -> Self {
+		Boundary {
+			separators: value.0,
+			symbols:    Symbols::Attach,
+			case:       true,
+			acronym:    true,
+			digit:      false,
+			acronyms:   Default::default(),
+		}
+	}
+}
+
+impl<'a, T: Copy + Eq + 'a> Boundary<'a, T> {
+	/// Only split on the given separators.
+	pub fn only(separators: &'a [T]) -> Self {
+		Boundary {
+			separators,
+			symbols:  Symbols::Attach,
+			case:     false,
+			acronym:  false,
+			digit:    false,
+			acronyms: Default::default(),
+		}
+	}
+}
+
+/// What happens to characters that are neither letters nor digits, and are
+/// not among the separators of a `Boundary`.
+///
+/// Runs of separators always collapse into a single one, and separators at the
+/// start or at the end are trimmed, `(USD)` to `usd`.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub enum Symbols {
+	/// They separate words, `Total Price (USD)` to `total_price_usd`.
+	#[default]
+	Separate,
+
+	/// They are dropped without separating words, `don't` to `dont`, while
+	/// whitespace still separates words.
+	Drop,
+
+	/// They are part of the word they're in, whitespace included, `a.b` to
+	/// `a.b`.
+	Attach,
+
+	/// Each run of them is a word on its own, `Price (USD)` to
+	/// `price_(_usd_)`, while whitespace still separates words.
+	Word,
+}
+
+/// How known acronyms are cased when joining words in camel case.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub enum Initialism {
+	/// Like any other word, `HttpUrl` and `httpUrl`.
+	#[default]
+	Title,
+
+	/// Always in upper case, `HTTPURL` and `httpURL`, like in Go.
+	Upper,
+
+	/// In upper case when at most two characters long, otherwise like any
+	/// other word, `IOStream` and `HttpUrl`, like in .NET.
+	Short,
+}
+
+/// A set of known acronyms, compared ignoring ASCII case.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct Acronyms<'a> {
+	/// The acronyms, in upper case.
+	pub list: &'a [&'a str],
+
+	/// How the acronyms are cased when joining words.
+	pub style: Initialism,
+}
+
+impl<'a> Acronyms<'a> {
+	/// Commonly used acronyms.
+	pub const COMMON: &'static [&'static str] = &[
+		"ACL", "API", "ASCII", "CPU", "CSS", "DNS", "EOF", "GUID", "HTML", "HTTP",
+		"HTTPS", "ID", "IO", "IP", "JSON", "LHS", "QPS", "RAM", "RHS", "RPC", "SLA",
+		"SMTP", "SQL", "SSH", "TCP", "TLS", "TTL", "UDP", "UI", "UID", "UUID", "URI",
+		"URL", "UTF8", "VM", "XML", "XMPP", "XSRF", "XSS",
+	];
+
+	/// Creates a set from the given acronyms.
+	pub const fn new(list: &'a [&'a str], style: Initialism) -> Self {
+		Acronyms { list, style }
+	}
+
+	/// Checks if the word is a known acronym.
+	#[inline(always)]
+	pub fn contains(&self, word: &[u8]) -> bool {
+		self.list.iter().any(|a| a.as_bytes().eq_ignore_ascii_case(word))
+	}
+
+	/// Splits a word made of more than one known acronym, pushing where each
+	/// acronym ends, moved by `offset`, from the last to the first.
+	pub(crate) fn split(&self, word: &[u8], offset: usize, ends: &mut Vec<usize>) -> bool {
+		if self.list.is_empty() {
+			return false;
+		}
+
+		// Only long runs of upper case characters need to allocate.
+		let mut small = [false; 64];
+		let mut large = Vec::new();
+
+		let covered = if word.len() < small.len() {
+			&mut small[.. word.len() + 1]
+		}
+		else {
+			large.resize(word.len() + 1, false);
+			&mut large[..]
+		};
+
+		self.cover(word, covered);
+
+		// The longest acronym leaving the rest covered, unless it's all that's
+		// left, `HTTPS` is split to `HTTP` and `S` when both are known.
+		let first     = ends.len();
+		let mut start = 0;
+
+		while start < word.len() {
+			let rest   = word.len() - start;
+			let length = self.list.iter()
+				.map(|a| a.len())
+				.filter(|&length| length > 0 && length < rest && covered[start + length] && self.contains(&word[start .. start + length]))
+				.max()
+				.unwrap_or(rest);
+
+			if start == 0 && length == rest {
+				return false;
+			}
+
+			start += length;
+			ends.push(offset + start);
+		}
+
+		ends[first ..].reverse();
+		true
+	}
+
+	/// Marks which suffixes of the given word are made only of known acronyms,
+	/// starting from the shortest so each one is checked only once.
+	fn cover(&self, word: &[u8], covered: &mut [bool]) {
+		covered[word.len()] = true;
+
+		for start in (0 .. word.len()).rev() {
+			covered[start] = self.list.iter().any(|a|
+				!a.is_empty() &&
+				a.len() <= word.len() - start &&
+				a.as_bytes().eq_ignore_ascii_case(&word[start .. start + a.len()]) &&
+				covered[start + a.len()]);
+		}
+	}
+}
+
+/// The kind of a word.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Kind {
+	/// All cased characters are lower case, `foo`.
+	Lower,
+
+	/// All cased characters are upper case, `FOO`.
+	Upper,
+
+	/// The first character is upper case and the rest lower case, `Foo`.
+	Title,
+
+	/// A run of upper case characters followed by a title cased word, `XML`
+	/// in `XMLHttpRequest`, or a known acronym in upper case.
+	Acronym,
+
+	/// Made only of digits, `42`.
+	Number,
+
+	/// Any other mix of cases, only possible when not splitting on case
+	/// changes, see `Boundary`.
+	Mixed,
+}
+
+/// A word within a string, borrowed from it.
+#[derive(Eq, PartialEq, Debug)]
+pub struct Word<'a, T: ?Sized + 'a> {
+	offset: usize,
+	text:   &'a T,
+	kind:   Kind,
+}
+
+impl<'a, T: ?Sized + 'a> Word<'a, T> {
+	#[inline(always)]
+	pub(crate) fn new(offset: usize, text: &'a T, kind: Kind) -> Self {
+		Word { offset, text, kind }
+	}
+
+	/// The offset of the word within the original string.
+	pub fn offset(&self) -> usize {
+		self.offset
+	}
+
+	/// The text of the word.
+	pub fn text(&self) -> &'a T {
+		self.text
+	}
+
+	/// The kind of the word.
+	pub fn kind(&self) -> Kind {
+		self.kind
+	}
+}
+
+// Derived implementations would require `T: Clone`, which `str` isn't.
+impl<'a, T: ?Sized + 'a> Clone for Word<'a, T> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<'a, T: ?Sized + 'a> Copy for Word<'a, T> { }
+
+impl<'a, T: ?Sized + 'a> Deref for Word<'a, T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		self.text
+	}
+}
+
+/// Iterator over the words of a string, see `Casing::words`.
+#[derive(Debug)]
+pub struct Words<'a, T: ?Sized + Casing + 'a> {
+	pub(crate) input:    &'a T,
+	pub(crate) offset:   usize,
+	pub(crate) boundary: Boundary<'a, T::Character>,
+
+	// Where the acronyms left in a split run of upper case characters end, so
+	// the run is only scanned once.
+	pub(crate) acronyms: Vec<usize>,
+}
+
+// A derived implementation would require `T: Clone`, which `str` isn't.
+impl<'a, T: ?Sized + Casing + 'a> Clone for Words<'a, T> {
+	fn clone(&self) -> Self {
+		Words { input: self.input, offset: self.offset, boundary: self.boundary, acronyms: self.acronyms.clone() }
+	}
+}
+
+impl<'a, T: ?Sized + Casing + 'a> Words<'a, T> {
+	#[inline(always)]
+	pub(crate) fn new(input: &'a T, boundary: Boundary<'a, T::Character>) -> Self {
+		Words { input, offset: 0, boundary, acronyms: Vec::new() }
+	}
+
+	/// The next acronym of a split run of upper case characters, if any is
+	/// left.
+	#[inline(always)]
+	pub(crate) fn acronym(&mut self) -> Option<Word<'a, T>> where T: Index<Range<usize>, Output = T> {
+		let end   = self.acronyms.pop()?;
+		let start = mem::replace(&mut self.offset, end);
+
+		Some(Word::new(start, &self.input[start .. end], Kind::Acronym))
+	}
+}
+
+/// How a word is cased when joining words back together.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub(crate) enum Pattern {
+	/// Leave the word as it is.
+	Keep,
+
+	/// Turn the whole word to lower case.
+	Lower,
+
+	/// Turn the whole word to upper case.
+	Upper,
+
+	/// Turn the first character to upper case and leave the rest as is.
+	Capital,
+
+	/// Turn the first character to upper case and the rest to lower case.
+	Title,
+}
+
+impl Pattern {
+	/// How the rest of a word is cased after its first part.
+	#[inline(always)]
+	pub fn rest(self) -> Pattern {
+		match self {
+			Pattern::Keep | Pattern::Capital => Pattern::Keep,
+			Pattern::Lower | Pattern::Title  => Pattern::Lower,
+			Pattern::Upper                   => Pattern::Upper,
+		}
+	}
+
+	/// Replaces the pattern for known acronyms following their style.
+	#[inline(always)]
+	pub fn acronym(self, acronyms: &Acronyms, word: &[u8], length: usize) -> Pattern {
+		match self {
+			Pattern::Capital | Pattern::Title if acronyms.contains(word) => match acronyms.style {
+				Initialism::Upper =>
+					Pattern::Upper,
+
+				Initialism::Short if length <= 2 =>
+					Pattern::Upper,
+
+				Initialism::Title | Initialism::Short =>
+					Pattern::Title,
+			},
+
+			pattern =>
+				pattern
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use {Acronyms, Initialism};
+
+	#[test]
+	fn split() {
+		fn split(acronyms: &Acronyms, word: &[u8]) -> Option<Vec<usize>> {
+			let mut ends = Vec::new();
+
+			if acronyms.split(word, 0, &mut ends) {
+				ends.reverse();
+				Some(ends)
+			}
+			else {
+				None
+			}
+		}
+
+		let acronyms = Acronyms::new(&["HTTP", "HTTPS", "S", "URL", "XML"], Initialism::Title);
+
+		assert_eq!(Some(vec![4, 7]), split(&acronyms, b"HTTPURL"));
+		assert_eq!(Some(vec![3, 7, 10]), split(&acronyms, b"XMLHTTPURL"));
+		assert_eq!(Some(vec![4, 5]), split(&acronyms, b"HTTPS"));
+		assert_eq!(Some(vec![5, 8]), split(&acronyms, b"HTTPSURL"));
+		assert_eq!(None, split(&acronyms, b"HTTP"));
+		assert_eq!(None, split(&acronyms, b"HTTPFOO"));
+	}
+
+	#[test]
+	fn split_overlapping() {
+		let acronyms = Acronyms::new(&["A", "AA", "AAA", "AAAA"], Initialism::Title);
+		let word     = [b'A'; 200];
+		let mut ends = Vec::new();
+
+		assert!(acronyms.split(&word, 0, &mut ends));
+		assert_eq!(Some(&4), ends.last());
+		assert_eq!(Some(&200), ends.first());
+		assert!(!acronyms.split(&[&word[..], b"B"].concat(), 0, &mut Vec::new()));
+	}
+}